@@ -1,4 +1,4 @@
-use crate::{assets::GameAssets, AppState, title_screen, ingame, game_state, splash};
+use crate::{assets::GameAssets, AppState, banter, commentary, cutscene_script, enemy_archetypes, localization, title_screen, ingame, game_state, splash, rounds};
 use bevy::{asset::Asset, ecs::system::SystemParam, gltf::Gltf, prelude::*};
 use bevy_kira_audio::AudioSource;
 use std::marker::PhantomData;
@@ -45,6 +45,7 @@ pub struct AssetsHandler<'w, 's> {
     pub images: ResMut<'w, Assets<Image>>,
     state: ResMut<'w, State<AppState>>,
     next_state: ResMut<'w, NextState>,
+    pub rounds: Res<'w, rounds::Rounds>,
 
     #[system_param(ignore)]
     phantom: PhantomData<&'s ()>,
@@ -85,6 +86,30 @@ impl<'w, 's> AssetsHandler<'w, 's> {
         self.add_asset(animation, path);
     }
 
+    pub fn add_enemy_archetypes(&mut self, archetypes: &mut Handle<enemy_archetypes::EnemyArchetypes>, path: &str) {
+        self.add_asset(archetypes, path);
+    }
+
+    pub fn add_rounds(&mut self, rounds: &mut Handle<rounds::RoundDefinitions>, path: &str) {
+        self.add_asset(rounds, path);
+    }
+
+    pub fn add_cutscene_scripts(&mut self, scripts: &mut Handle<cutscene_script::CutsceneScripts>, path: &str) {
+        self.add_asset(scripts, path);
+    }
+
+    pub fn add_commentary(&mut self, commentary: &mut Handle<commentary::CommentaryLines>, path: &str) {
+        self.add_asset(commentary, path);
+    }
+
+    pub fn add_banter(&mut self, banter: &mut Handle<banter::Banters>, path: &str) {
+        self.add_asset(banter, path);
+    }
+
+    pub fn add_languages(&mut self, languages: &mut Handle<localization::Languages>, path: &str) {
+        self.add_asset(languages, path);
+    }
+
     pub fn add_standard_mesh(&mut self, handle: &mut Handle<Mesh>, mesh: Mesh) {
         *handle = self.meshes.add(mesh);
     }
@@ -1,4 +1,4 @@
-use crate::asset_loading;
+use crate::{asset_loading, banter, commentary, cutscene_script, enemy_archetypes, localization, rounds};
 use bevy::gltf::Gltf;
 use bevy::prelude::*;
 use bevy_kira_audio::AudioSource;
@@ -16,10 +16,16 @@ pub struct GameAssets {
     pub person: Handle<Gltf>,
     pub person_blood: Handle<Gltf>,
     pub enemy: Handle<Gltf>,
+    pub enemy_archetypes: Handle<enemy_archetypes::EnemyArchetypes>,
     pub person_dive: Handle<AnimationClip>,
     pub person_run: Handle<AnimationClip>,
     pub person_idle: Handle<AnimationClip>,
     pub maze: Handle<Gltf>,
+    pub rounds: Handle<rounds::RoundDefinitions>,
+    pub cutscenes: Handle<cutscene_script::CutsceneScripts>,
+    pub commentary: Handle<commentary::CommentaryLines>,
+    pub banter: Handle<banter::Banters>,
+    pub languages: Handle<localization::Languages>,
     pub football: Handle<Gltf>,
     pub corn_stalk: Handle<Gltf>,
     pub corn_stalk_material: Handle<StandardMaterial>,
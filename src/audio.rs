@@ -1,3 +1,4 @@
+use crate::settings::Settings;
 use bevy::{ecs::system::SystemParam, prelude::*};
 use bevy_kira_audio::{AudioApp, AudioChannel, AudioPlugin, AudioSource, AudioControl};
 use std::marker::PhantomData;
@@ -16,40 +17,57 @@ pub struct MusicChannel;
 pub struct SoundChannel;
 pub struct TalkChannel;
 
+// channels are optional so gameplay can run without an audio backend (see headless.rs)
 #[derive(SystemParam)]
 pub struct GameAudio<'w, 's> {
-    music_channel: Res<'w, AudioChannel<MusicChannel>>,
-    sound_channel: Res<'w, AudioChannel<SoundChannel>>,
-    talk_channel: Res<'w, AudioChannel<TalkChannel>>,
+    music_channel: Option<Res<'w, AudioChannel<MusicChannel>>>,
+    sound_channel: Option<Res<'w, AudioChannel<SoundChannel>>>,
+    talk_channel: Option<Res<'w, AudioChannel<TalkChannel>>>,
 
     #[system_param(ignore)]
     phantom: PhantomData<&'s ()>,
 }
 
 impl<'w, 's> GameAudio<'w, 's> {
-    pub fn set_volume(&mut self) {
-        self.sound_channel.set_volume(0.2);
-        self.talk_channel.set_volume(0.2);
-        self.music_channel.set_volume(0.5);
+    pub fn set_volume(&mut self, settings: &Settings) {
+        if let Some(sound_channel) = &self.sound_channel {
+            sound_channel.set_volume(settings.sound_volume as f64);
+        }
+        if let Some(talk_channel) = &self.talk_channel {
+            talk_channel.set_volume(settings.talk_volume as f64);
+        }
+        if let Some(music_channel) = &self.music_channel {
+            music_channel.set_volume(settings.music_volume as f64);
+        }
     }
     pub fn play_bgm(&mut self, handle: &Handle<AudioSource>) {
-        self.music_channel.stop();
-        self.music_channel.play(handle.clone()).looped();
+        if let Some(music_channel) = &self.music_channel {
+            music_channel.stop();
+            music_channel.play(handle.clone()).looped();
+        }
     }
 
     pub fn play_bgm_once(&mut self, handle: &Handle<AudioSource>) {
-        self.music_channel.stop();
-        self.music_channel.play(handle.clone());
+        if let Some(music_channel) = &self.music_channel {
+            music_channel.stop();
+            music_channel.play(handle.clone());
+        }
     }
 
     pub fn stop_bgm(&mut self) {
-        self.music_channel.stop();
+        if let Some(music_channel) = &self.music_channel {
+            music_channel.stop();
+        }
     }
 
     pub fn play_sfx(&mut self, handle: &Handle<AudioSource>) {
-        self.sound_channel.play(handle.clone());
+        if let Some(sound_channel) = &self.sound_channel {
+            sound_channel.play(handle.clone());
+        }
     }
     pub fn play_talk(&mut self, handle: &Handle<AudioSource>) {
-        self.talk_channel.play(handle.clone());
+        if let Some(talk_channel) = &self.talk_channel {
+            talk_channel.play(handle.clone());
+        }
     }
 }
@@ -1,10 +1,25 @@
 use bevy::ecs::system::SystemParam;
 use bevy::render::primitives::Aabb;
 use bevy::prelude::*;
+use std::collections::HashMap;
 use crate::{
-    LEFT_END, RIGHT_END, BOTTOM_END, TOP_END,
+    LEFT_END, RIGHT_END, BOTTOM_END, TOP_END, timestep,
 };
 
+pub struct CollisionPlugin;
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CollisionGrid>()
+            // corn is harvested during gameplay steps so the grid is brought up to date at
+            // the start of every step as well as once a frame
+            .add_system_to_stage(
+                timestep::FixedUpdateStage,
+                update_collision_grid.exclusive_system().at_start().label(UpdateCollisionGrid)
+            )
+            .add_system_to_stage(CoreStage::PostUpdate, update_collision_grid);
+    }
+}
+
 #[derive(Component)]
 pub struct Collidable {
     pub aabb: WorldAabb,
@@ -19,14 +34,143 @@ pub struct WorldAabb {
     pub max: Vec3,
 }
 
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub struct UpdateCollisionGrid;
+
+const CELL_SIZE: f32 = 2.0;
+// past this many changes at once (like the whole maze being spawned) it's quicker for anything
+// following along to start over than to go through them one at a time
+const MAX_TRACKED_CHANGES: usize = 256;
+
+// Uniform grid over the x/z plane so lookups only check the Collidables in nearby cells
+// instead of every corn stalk in the maze.
+#[derive(Default)]
+pub struct CollisionGrid {
+    cells: HashMap<(i32, i32), Vec::<Entity>>,
+    indexed: HashMap<Entity, WorldAabb>,
+    version: u64,
+    // where Collidables were added, moved or removed since take_changes was last called,
+    // None when that's everywhere
+    changes: Option<Vec::<WorldAabb>>,
+}
+
+impl CollisionGrid {
+    fn cell(position: Vec3) -> (i32, i32) {
+        ((position.x / CELL_SIZE).floor() as i32, (position.z / CELL_SIZE).floor() as i32)
+    }
+
+    fn cells_overlapping(aabb: &WorldAabb) -> impl Iterator<Item = (i32, i32)> {
+        let (min_x, min_z) = CollisionGrid::cell(aabb.min);
+        let (max_x, max_z) = CollisionGrid::cell(aabb.max);
+        (min_x..=max_x).flat_map(move |x| (min_z..=max_z).map(move |z| (x, z)))
+    }
+
+    fn insert(&mut self, entity: Entity, aabb: WorldAabb) {
+        self.remove(entity);
+        for cell in CollisionGrid::cells_overlapping(&aabb) {
+            self.cells.entry(cell).or_default().push(entity);
+        }
+        self.indexed.insert(entity, aabb);
+        self.version += 1;
+        self.track_change(aabb);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        if let Some(aabb) = self.indexed.remove(&entity) {
+            for cell in CollisionGrid::cells_overlapping(&aabb) {
+                if let Some(entities) = self.cells.get_mut(&cell) {
+                    entities.retain(|e| *e != entity);
+                    if entities.is_empty() {
+                        self.cells.remove(&cell);
+                    }
+                }
+            }
+            self.version += 1;
+            self.track_change(aabb);
+        }
+    }
+
+    fn track_change(&mut self, aabb: WorldAabb) {
+        if let Some(changes) = &mut self.changes {
+            if changes.len() < MAX_TRACKED_CHANGES {
+                changes.push(aabb);
+            } else {
+                self.changes = None;
+            }
+        }
+    }
+
+    // the areas that changed since the last call, None if too much changed to keep track of
+    pub fn take_changes(&mut self) -> Option<Vec::<WorldAabb>> {
+        self.changes.replace(vec![])
+    }
+
+    // entities whose cells overlap the area, may include ones that don't overlap it exactly
+    pub fn query(&self, area: &WorldAabb) -> Vec::<Entity> {
+        let mut found = vec![];
+        for cell in CollisionGrid::cells_overlapping(area) {
+            if let Some(entities) = self.cells.get(&cell) {
+                for entity in entities {
+                    if !found.contains(entity) {
+                        found.push(*entity);
+                    }
+                }
+            }
+        }
+
+        found
+    }
+
+    // goes up whenever a Collidable is added, moved or removed
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indexed.is_empty()
+    }
+
+    pub fn aabbs(&self) -> impl Iterator<Item = &WorldAabb> {
+        self.indexed.values()
+    }
+
+    pub fn aabb(&self, entity: Entity) -> Option<&WorldAabb> {
+        self.indexed.get(&entity)
+    }
+}
+
+fn update_collision_grid(
+    mut grid: ResMut<CollisionGrid>,
+    changed: Query<(Entity, &Collidable), Changed<Collidable>>,
+    removed: RemovedComponents<Collidable>,
+) {
+    for entity in removed.iter() {
+        grid.remove(entity);
+    }
+
+    for (entity, collidable) in &changed {
+        grid.insert(entity, collidable.aabb);
+    }
+}
+
 #[derive(SystemParam)]
 pub struct Collidables<'w, 's> {
+    grid: Res<'w, CollisionGrid>,
     collidables: Query<'w, 's, &'static Collidable>,
     dynamic_collidables: Query<'w, 's, (Entity, &'static DynamicCollidable)>,
     aabbs: Query<'w, 's, (&'static Aabb, &'static GlobalTransform)>,
 }
 
 impl<'w, 's> Collidables<'w, 's> {
+    // Collidables from the grid cells overlapping the area. Anything removed since the grid
+    // was last updated is skipped because it no longer has a Collidable.
+    fn nearby<'a>(&'a self, area: &WorldAabb) -> impl Iterator<Item = &'a Collidable> + 'a {
+        self.grid
+            .query(area)
+            .into_iter()
+            .filter_map(move |entity| self.collidables.get(entity).ok())
+    }
+
     pub fn is_in_collidable(&self, position: &Vec3) -> bool {
         if position.z <= LEFT_END ||
            position.z >= RIGHT_END ||
@@ -35,11 +179,11 @@ impl<'w, 's> Collidables<'w, 's> {
            return true;
         }
 
-        if self.collidables.iter().count() == 0 && self.dynamic_collidables.iter().count() == 0 {
+        if self.grid.is_empty() && self.dynamic_collidables.is_empty() {
             return false;
         }
 
-        for collidable in self.collidables.iter() {
+        for collidable in self.nearby(&WorldAabb { min: *position, max: *position }) {
             if position.x <= collidable.aabb.max.x
                 && position.x >= collidable.aabb.min.x
                 && position.z <= collidable.aabb.max.z
@@ -73,46 +217,37 @@ impl<'w, 's> Collidables<'w, 's> {
         return false;
     }
 
-    pub fn fit_in(&self, current: &Vec3, new: &mut Vec3, velocity: &mut Vec3, time: &Res<Time>) {
-        if self.collidables.iter().count() == 0 && self.dynamic_collidables.iter().count() == 0 {
-            return;
-        }
+    // Moves a circle from current towards new, stopping at and sliding along any corn in the way
+    // instead of checking only where it ends up, so nothing tunnels through at high speed.
+    pub fn fit_in(&self, current: &Vec3, new: &mut Vec3, velocity: &mut Vec3) {
+        let start = Vec2::new(current.x, current.z);
+        let delta = Vec2::new(new.x, new.z) - start;
 
-        if new.z <= LEFT_END {
-            *new = *current;
-            *velocity = Vec3::new(velocity.x, velocity.y, velocity.z.abs().max(1.0) * 2.0);
-            return; 
-        }
-        if new.z >= RIGHT_END {
-            *new = *current;
-            *velocity = Vec3::new(velocity.x, velocity.y, (velocity.z.abs() * -1.0).min(-1.0) * 2.0);
-            return; 
-        }
-        if new.x <= BOTTOM_END {
-            *new = *current;
-            *velocity = Vec3::new(velocity.x.abs().max(1.0) * 2.0, velocity.y, velocity.z);
-            return; 
-        }
-        if new.x >= TOP_END {
-            *new = *current;
-            *velocity = Vec3::new((velocity.x.abs() * -1.0).min(-1.0) * 2.0, velocity.y, velocity.z);
-            return; 
-        }
+        let swept_area = WorldAabb {
+            min: current.min(*new) - Vec3::splat(MOVER_RADIUS),
+            max: current.max(*new) + Vec3::splat(MOVER_RADIUS),
+        };
+        let boxes = self.nearby(&swept_area)
+                        .map(|collidable| (Vec2::new(collidable.aabb.min.x, collidable.aabb.min.z) - Vec2::splat(MOVER_RADIUS),
+                                           Vec2::new(collidable.aabb.max.x, collidable.aabb.max.z) + Vec2::splat(MOVER_RADIUS)))
+                        .collect::<Vec::<_>>();
 
-        let mut is_valid = true;
-        let mut current_aabbs = vec![];
+        // already overlapping something, e.g. spawned inside the maze
+        let (start, mut normals) = depenetrate(start, &boxes);
+        let (end, slide_normals) = slide(start, delta, &boxes);
+        normals.extend(slide_normals);
 
-        for collidable in self.collidables.iter() {
-            if new.x <= collidable.aabb.max.x
-                && new.x >= collidable.aabb.min.x
-                && new.z <= collidable.aabb.max.z
-                && new.z >= collidable.aabb.min.z
-            {
-                is_valid = false;
-                current_aabbs.push(collidable.aabb);
-            }
+        let mut planar_velocity = Vec2::new(velocity.x, velocity.z);
+        for normal in normals.iter() {
+            planar_velocity = remove_inward(planar_velocity, *normal);
         }
 
+        new.x = end.x;
+        new.z = end.y;
+        velocity.x = planar_velocity.x;
+        velocity.z = planar_velocity.y;
+
+        // combine blades are rotated so they're pushed out of in their own space
         let dynamic_collidables = self.dynamic_collidables
                                       .iter()
                                       .filter_map(|(entity, _)| self.aabbs.get(entity).ok());
@@ -120,70 +255,321 @@ impl<'w, 's> Collidables<'w, 's> {
         for (aabb, global_transform) in dynamic_collidables {
             let matrix = global_transform.compute_matrix();
             let inverse_matrix = matrix.inverse();
-            let min = aabb.min();
-            let max = aabb.max();
+            let min = Vec3::from(aabb.min());
+            let max = Vec3::from(aabb.max());
 
-            let transformed_new = inverse_matrix.transform_point3(*new);
+            let local = inverse_matrix.transform_point3(*new);
+            let local_box = (Vec2::new(min.x, min.z), Vec2::new(max.x, max.z));
+            let (pushed_out, local_normals) = depenetrate(Vec2::new(local.x, local.z), &[local_box]);
 
-            if transformed_new.x <= max.x
-                && transformed_new.x >= min.x
-                && transformed_new.z <= max.z
-                && transformed_new.z >= min.z
-            {
-                // bounce away I guess
-                *new = *current;
-                *velocity = Vec3::new(-velocity.x, -velocity.y, -velocity.z) * 2.0;
-                return; 
+            if let Some(local_normal) = local_normals.first() {
+                *new = matrix.transform_point3(Vec3::new(pushed_out.x, local.y, pushed_out.y));
+                let normal = matrix.transform_vector3(Vec3::new(local_normal.x, 0.0, local_normal.y));
+                let normal = Vec2::new(normal.x, normal.z).normalize_or_zero();
+                let planar_velocity = remove_inward(Vec2::new(velocity.x, velocity.z), normal);
+                velocity.x = planar_velocity.x;
+                velocity.z = planar_velocity.y;
             }
         }
 
-        if is_valid {
-            return;
+        bounce_off_bounds(new, velocity);
+    }
+}
+
+// how far movers are kept from corn, on top of the corn's own thickness
+pub const MOVER_RADIUS: f32 = 0.25;
+// gap left between a mover and whatever it stopped against so it doesn't start the next step inside it
+const SKIN: f32 = 0.001;
+const MAX_SLIDES: usize = 4;
+const MAX_DEPENETRATION_STEPS: usize = 8;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SweepHit {
+    pub time: f32,
+    pub normal: Vec2,
+}
+
+// Slab test of a point moving by delta against a box, the box is expected to already be grown
+// by the mover's radius. Returns when in 0..1 the point first touches the box and the face normal,
+// None if it misses or starts inside.
+pub fn sweep_point_aabb(start: Vec2, delta: Vec2, min: Vec2, max: Vec2) -> Option<SweepHit> {
+    let start = start.to_array();
+    let delta = delta.to_array();
+    let min = min.to_array();
+    let max = max.to_array();
+
+    let mut entry_time = 0.0_f32;
+    let mut exit_time = 1.0_f32;
+    let mut normal = Vec2::ZERO;
+
+    for axis in 0..2 {
+        if delta[axis].abs() <= f32::EPSILON {
+            if start[axis] <= min[axis] || start[axis] >= max[axis] {
+                return None;
+            }
+            continue;
         }
 
-        let get_sign = |num: f32| {
-            let sign = num.signum();
-            if sign.is_nan() {
-                1.0
-            } else {
-                sign
+        let near = if delta[axis] > 0.0 { min[axis] } else { max[axis] };
+        let far = if delta[axis] > 0.0 { max[axis] } else { min[axis] };
+        let near_time = (near - start[axis]) / delta[axis];
+        let far_time = (far - start[axis]) / delta[axis];
+
+        if near_time > entry_time {
+            entry_time = near_time;
+            let mut axis_normal = [0.0, 0.0];
+            axis_normal[axis] = -delta[axis].signum();
+            normal = Vec2::from(axis_normal);
+        }
+        exit_time = exit_time.min(far_time);
+
+        if entry_time >= exit_time {
+            return None;
+        }
+    }
+
+    if normal == Vec2::ZERO {
+        return None;
+    }
+
+    Some(SweepHit {
+        time: entry_time,
+        normal,
+    })
+}
+
+// Moves from start by delta, stopping at the first box hit and sliding the rest of the way along it.
+// Returns where it ended up and the normals of everything it slid along.
+pub fn slide(start: Vec2, delta: Vec2, boxes: &[(Vec2, Vec2)]) -> (Vec2, Vec::<Vec2>) {
+    let mut position = start;
+    let mut remaining = delta;
+    let mut normals = vec![];
+
+    for _ in 0..MAX_SLIDES {
+        if remaining.length_squared() <= f32::EPSILON {
+            break;
+        }
+
+        let first_hit = boxes.iter()
+                             .filter_map(|(min, max)| sweep_point_aabb(position, remaining, *min, *max))
+                             .min_by(|a, b| a.time.partial_cmp(&b.time).unwrap_or(std::cmp::Ordering::Equal));
+
+        match first_hit {
+            Some(hit) => {
+                position += remaining * hit.time + hit.normal * SKIN;
+                let leftover = remaining * (1.0 - hit.time);
+                remaining = leftover - hit.normal * leftover.dot(hit.normal);
+                normals.push(hit.normal);
+            },
+            None => {
+                position += remaining;
+                remaining = Vec2::ZERO;
             }
-        };
+        }
+    }
+
+    (position, normals)
+}
+
+// Pushes a point out of any boxes it's inside of along whichever side is closest, preferring a
+// way out that doesn't land it in a neighbouring box, until it's clear of all of them or gives up.
+pub fn depenetrate(position: Vec2, boxes: &[(Vec2, Vec2)]) -> (Vec2, Vec::<Vec2>) {
+    let mut position = position;
+    let mut normals = vec![];
 
-        let mut temp_new = *current;
-        for aabb in current_aabbs.iter() {
-            let top_normal = (Vec3::new(aabb.max.x, 0.0, aabb.min.z)
-                            - Vec3::new(aabb.max.x, 0.0, aabb.max.z)).cross(Vec3::Y).normalize();
-            let bottom_normal = (Vec3::new(aabb.min.x, 0.0, aabb.max.z)
-                               - Vec3::new(aabb.min.x, 0.0, aabb.min.z)).cross(Vec3::Y).normalize();
-            let left_normal = (Vec3::new(aabb.min.x, 0.0, aabb.min.z)
-                             - Vec3::new(aabb.max.x, 0.0, aabb.min.z)).cross(Vec3::Y).normalize();
-            let right_normal = (Vec3::new(aabb.max.x, 0.0, aabb.max.z)
-                              - Vec3::new(aabb.min.x, 0.0, aabb.max.z)).cross(Vec3::Y).normalize();
-
-            let normalized_current = (*new - temp_new).normalize();
-            if top_normal.dot(normalized_current) < 0.0 && temp_new.x > aabb.max.x {
-                let undesired = (velocity.x.abs().max(1.0) * top_normal) * normalized_current.dot(top_normal);
-                *new = temp_new - (undesired * time.delta_seconds());
-                velocity.x = 0.0;
-                velocity.z = velocity.z + (get_sign(velocity.z) * velocity.x);
-            } else if bottom_normal.dot(normalized_current) < 0.0 && temp_new.x < aabb.min.x {
-                let undesired = (velocity.x.abs().max(1.0) * bottom_normal) * normalized_current.dot(bottom_normal);
-                *new = temp_new - (undesired * time.delta_seconds());
-                velocity.x = 0.0;
-                velocity.z = velocity.z + (get_sign(velocity.z) * velocity.x);
-            } else if left_normal.dot(normalized_current) < 0.0 && temp_new.z < aabb.min.z {
-                let undesired = (velocity.z.abs().max(1.0) * left_normal) * normalized_current.dot(left_normal);
-                *new = temp_new - (undesired * time.delta_seconds());
-                velocity.x = velocity.x + (get_sign(velocity.x) * velocity.z);
-                velocity.z = 0.0;
-            } else if right_normal.dot(normalized_current) < 0.0 && temp_new.z > aabb.max.z {
-                let undesired = (velocity.z.abs().max(1.0) * right_normal) * normalized_current.dot(right_normal);
-                *new = temp_new - (undesired * time.delta_seconds());
-                velocity.x = velocity.x + (get_sign(velocity.x) * velocity.z);
-                velocity.z = 0.0;
-            } 
+    for _ in 0..MAX_DEPENETRATION_STEPS {
+        let mut exits = boxes.iter()
+                             .filter(|bounds| is_inside(position, bounds))
+                             .flat_map(|(min, max)| [
+                                 (position.x - min.x, Vec2::new(-1.0, 0.0)),
+                                 (max.x - position.x, Vec2::new(1.0, 0.0)),
+                                 (position.y - min.y, Vec2::new(0.0, -1.0)),
+                                 (max.y - position.y, Vec2::new(0.0, 1.0)),
+                             ])
+                             .collect::<Vec::<_>>();
+        if exits.is_empty() {
+            break;
         }
+        exits.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        // stalks next to each other would otherwise push it back and forth between them
+        let (distance, normal) = exits.iter()
+                                      .copied()
+                                      .find(|(distance, normal)| {
+                                          let pushed_out = position + *normal * (*distance + SKIN);
+                                          !boxes.iter().any(|bounds| is_inside(pushed_out, bounds))
+                                      })
+                                      .unwrap_or(exits[0]);
+
+        position += normal * (distance + SKIN);
+        normals.push(normal);
+    }
+
+    (position, normals)
+}
+
+fn is_inside(point: Vec2, (min, max): &(Vec2, Vec2)) -> bool {
+    point.x > min.x && point.x < max.x && point.y > min.y && point.y < max.y
+}
+
+// takes out the part of the velocity heading into a surface, leaving what runs along it
+pub fn remove_inward(velocity: Vec2, normal: Vec2) -> Vec2 {
+    let into = velocity.dot(normal);
+    if into < 0.0 {
+        velocity - normal * into
+    } else {
+        velocity
+    }
+}
+
+// Anything leaving the field is put back on the line and bounced back in. Each axis is handled
+// the same way so hitting a corner bounces off both sides.
+pub fn bounce_off_bounds(position: &mut Vec3, velocity: &mut Vec3) {
+    let bounce = |speed: f32| speed.abs().max(1.0) * 2.0;
+
+    if position.z <= LEFT_END {
+        position.z = LEFT_END + SKIN;
+        velocity.z = bounce(velocity.z);
+    } else if position.z >= RIGHT_END {
+        position.z = RIGHT_END - SKIN;
+        velocity.z = -bounce(velocity.z);
+    }
+
+    if position.x <= BOTTOM_END {
+        position.x = BOTTOM_END + SKIN;
+        velocity.x = bounce(velocity.x);
+    } else if position.x >= TOP_END {
+        position.x = TOP_END - SKIN;
+        velocity.x = -bounce(velocity.x);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stalk(min: (f32, f32), max: (f32, f32)) -> (Vec2, Vec2) {
+        (Vec2::new(min.0, min.1), Vec2::new(max.0, max.1))
+    }
+
+    #[test]
+    fn sweep_hits_a_box_exactly_on_its_corner() {
+        let (min, max) = stalk((0.0, 0.0), (1.0, 1.0));
+        let hit = sweep_point_aabb(Vec2::new(-1.0, -1.0), Vec2::new(2.0, 2.0), min, max)
+                      .expect("heading straight at the corner");
+
+        assert_eq!(hit.time, 0.5);
+        assert!(hit.normal == Vec2::new(-1.0, 0.0) || hit.normal == Vec2::new(0.0, -1.0));
+    }
+
+    #[test]
+    fn sweep_along_an_edge_misses() {
+        let (min, max) = stalk((0.0, 0.0), (1.0, 1.0));
+        assert_eq!(sweep_point_aabb(Vec2::new(-1.0, 1.0), Vec2::new(3.0, 0.0), min, max), None);
+    }
+
+    #[test]
+    fn slide_into_a_corner_goes_around_it() {
+        let boxes = [stalk((0.0, 0.0), (1.0, 1.0))];
+        let (end, normals) = slide(Vec2::new(-1.0, -1.0), Vec2::new(2.0, 2.0), &boxes);
+
+        assert!(!is_inside(end, &boxes[0]));
+        assert_eq!(normals.len(), 1);
+        assert!((end.y - 1.0).abs() < 1e-5 || (end.x - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn depenetrate_pushes_out_of_two_stalks_side_by_side() {
+        let boxes = [stalk((0.0, 0.0), (1.0, 1.0)), stalk((1.0, 0.0), (2.0, 1.0))];
+        let (position, normals) = depenetrate(Vec2::new(0.95, 0.5), &boxes);
+
+        assert!(!normals.is_empty());
+        for bounds in boxes.iter() {
+            assert!(!is_inside(position, bounds), "{:?} is still inside {:?}", position, bounds);
+        }
+    }
+
+    #[test]
+    fn depenetrate_pushes_out_of_three_overlapping_stalks() {
+        let boxes = [
+            stalk((0.0, 0.0), (1.0, 1.0)),
+            stalk((0.8, 0.0), (1.8, 1.0)),
+            stalk((0.0, 0.8), (1.0, 1.8)),
+        ];
+        let (position, normals) = depenetrate(Vec2::new(0.9, 0.9), &boxes);
+
+        assert!(!normals.is_empty());
+        for bounds in boxes.iter() {
+            assert!(!is_inside(position, bounds), "{:?} is still inside {:?}", position, bounds);
+        }
+    }
+
+    #[test]
+    fn depenetrate_leaves_a_clear_point_alone() {
+        let boxes = [stalk((0.0, 0.0), (1.0, 1.0))];
+        let (position, normals) = depenetrate(Vec2::new(2.0, 2.0), &boxes);
+
+        assert_eq!(position, Vec2::new(2.0, 2.0));
+        assert!(normals.is_empty());
+    }
+
+    #[test]
+    fn fast_mover_stops_at_a_stalk_thinner_than_its_step() {
+        let boxes = [stalk((0.0, 0.0), (0.5, 0.5))];
+        let (end, normals) = slide(Vec2::new(-5.0, 0.25), Vec2::new(20.0, 0.0), &boxes);
+
+        assert!(end.x < 0.0, "tunnelled through to {:?}", end);
+        assert_eq!(normals, vec![Vec2::new(-1.0, 0.0)]);
+    }
+
+    #[test]
+    fn sliding_along_a_wall_keeps_the_tangential_velocity() {
+        let boxes = [stalk((0.0, -10.0), (1.0, 10.0))];
+        let (end, normals) = slide(Vec2::new(-0.5, 0.0), Vec2::new(1.0, 1.0), &boxes);
+
+        assert!(end.x < 0.0);
+        assert!((end.y - 1.0).abs() < 1e-5, "only got to {:?}", end);
+        assert_eq!(normals, vec![Vec2::new(-1.0, 0.0)]);
+
+        assert_eq!(remove_inward(Vec2::new(3.0, 4.0), normals[0]), Vec2::new(0.0, 4.0));
+        // already heading away
+        assert_eq!(remove_inward(Vec2::new(-3.0, 4.0), normals[0]), Vec2::new(-3.0, 4.0));
+    }
+
+    #[test]
+    fn bounces_back_in_off_every_end() {
+        let mut position = Vec3::new(0.0, 0.0, LEFT_END - 1.0);
+        let mut velocity = Vec3::new(0.0, 0.0, -5.0);
+        bounce_off_bounds(&mut position, &mut velocity);
+        assert_eq!(position.z, LEFT_END + SKIN);
+        assert_eq!(velocity.z, 10.0);
+
+        let mut position = Vec3::new(0.0, 0.0, RIGHT_END + 1.0);
+        let mut velocity = Vec3::new(0.0, 0.0, 5.0);
+        bounce_off_bounds(&mut position, &mut velocity);
+        assert_eq!(position.z, RIGHT_END - SKIN);
+        assert_eq!(velocity.z, -10.0);
+
+        let mut position = Vec3::new(BOTTOM_END - 1.0, 0.0, 0.0);
+        let mut velocity = Vec3::new(-5.0, 0.0, 0.0);
+        bounce_off_bounds(&mut position, &mut velocity);
+        assert_eq!(position.x, BOTTOM_END + SKIN);
+        assert_eq!(velocity.x, 10.0);
+
+        let mut position = Vec3::new(TOP_END + 1.0, 0.0, 0.0);
+        let mut velocity = Vec3::new(5.0, 0.0, 0.0);
+        bounce_off_bounds(&mut position, &mut velocity);
+        assert_eq!(position.x, TOP_END - SKIN);
+        assert_eq!(velocity.x, -10.0);
+    }
+
+    #[test]
+    fn bounces_off_both_sides_in_a_corner_even_when_barely_moving() {
+        let mut position = Vec3::new(TOP_END, 0.0, LEFT_END);
+        let mut velocity = Vec3::ZERO;
+        bounce_off_bounds(&mut position, &mut velocity);
 
+        assert_eq!(position, Vec3::new(TOP_END - SKIN, 0.0, LEFT_END + SKIN));
+        assert_eq!(velocity, Vec3::new(-2.0, 0.0, 2.0));
     }
 }
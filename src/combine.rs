@@ -1,11 +1,13 @@
 use bevy::prelude::*;
 use crate::{
     AppState, maze::CornStalk, assets::GameAssets, component_adder::AnimationLink, maze,
-    collision, game_state, ZeroSignum, football, player, enemy, cutscene, audio::GameAudio,
+    collision, game_state, ZeroSignum, football, player, enemy, cutscene, commentary, audio::GameAudio, rng, timestep, rounds,
+    harvest_strategy::{HarvestContext, HarvestStrategy, LANE_WIDTH},
+    TOP_END, BOTTOM_END,
 };
 use bevy::render::primitives::Aabb;
-use rand::thread_rng;
-use rand::prelude::SliceRandom;
+use rand::{rngs::StdRng, seq::SliceRandom};
+use std::collections::{HashMap, HashSet};
 use std::f32::consts::{TAU, PI};
 
 pub struct CombinePlugin;
@@ -14,6 +16,10 @@ impl Plugin for CombinePlugin {
         app.add_system_set(
             SystemSet::on_update(AppState::InGame)
                 .with_system(animate_combine)
+        )
+        .add_system_set_to_stage(
+            timestep::FixedUpdateStage,
+            timestep::gameplay()
                 .with_system(harvest_corn)
                 .with_system(detect_blade_collisions)
                 .with_system(handle_corn_collision)
@@ -35,6 +41,17 @@ impl Default for Heading {
     }
 }
 
+impl Heading {
+    pub fn rotation(&self) -> Quat {
+        match self {
+            Heading::Left => Quat::from_rotation_y(TAU * 0.25),
+            Heading::Right => Quat::from_rotation_y(TAU * 0.75),
+            Heading::Up => Quat::from_rotation_y(0.0),
+            Heading::Down => Quat::from_rotation_y(TAU * 0.5),
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct Combine {
     pub animation_set: bool,
@@ -43,27 +60,64 @@ pub struct Combine {
     pub speed: f32,
     pub current_rotation_time: f32,
     pub target_rotation: Quat,
+    // the lane it's cutting or on its way to, no other combine picks a lane near it
     pub target_x_coordinate: f32,
     pub friction: f32,
+    pub strategy: Box<dyn HarvestStrategy>,
 }
 
 impl Combine {
-    pub fn create(latest: bool) -> Self {
+    pub fn create(latest: bool, definition: &rounds::CombineDefinition, starting_lane: f32) -> Self {
         Combine {
             animation_set: false,
             velocity: Vec3::default(),
-            speed: if latest { 40.0 } else { 30.0 },
+            speed: if latest { definition.speed } else { definition.speed * 0.75 },
             current_rotation_time: 0.0,
             heading: Heading::Left,
-            target_rotation: Quat::from_rotation_y(TAU * 0.25),
-            target_x_coordinate: 0.0,
+            target_rotation: Heading::Left.rotation(),
+            target_x_coordinate: starting_lane,
             friction: 0.01,
+            strategy: definition.pattern.strategy(),
         }
     }
+
+    // starts turning to face the new heading, it doesn't drive again until it's done
+    fn turn(&mut self, heading: Heading) {
+        self.target_rotation = heading.rotation();
+        self.heading = heading;
+        self.current_rotation_time = 0.0;
+    }
+
+    // heads back across the field towards whichever side it isn't on
+    fn start_pass(&mut self, position: Vec3) {
+        self.velocity.x = 0.0;
+        self.turn(if position.z > 0.0 { Heading::Left } else { Heading::Right });
+    }
 }
 
-#[derive(Component)]
-pub struct CombineBlade;
+// Lanes for the combines to start in, spread out across the field. If corn has already been
+// cut they start on random unharvested stalks as long as it keeps them out of each other's way.
+pub fn starting_lanes(count: usize, unharvested: &[Vec3], rng: &mut StdRng) -> Vec::<f32> {
+    let mut lanes = Vec::<f32>::new();
+    for i in 0..count {
+        let spread = TOP_END * 0.5 - i as f32 * (TOP_END - BOTTOM_END) / count as f32;
+        let free = unharvested.iter()
+                              .filter(|corn| lanes.iter().all(|lane| (corn.x - lane).abs() > LANE_WIDTH))
+                              .collect::<Vec::<_>>();
+        lanes.push(free.choose(rng).map(|corn| corn.x).unwrap_or(spread));
+    }
+
+    lanes
+}
+
+#[derive(Component, Default)]
+pub struct CombineBlade {
+    // the player was just inside NEAR_MISS_DISTANCE of the hitbox
+    player_close: bool,
+}
+
+// how far outside the blade hitbox still counts as cutting it close
+const NEAR_MISS_DISTANCE: f32 = 1.0;
 
 const CORN_CUT_DISTANCE: f32 = 0.7;
 fn handle_corn_collision( 
@@ -73,7 +127,7 @@ fn handle_corn_collision(
     mut game_assets: ResMut<GameAssets>,
     mut audio: GameAudio,
     mut sound_cooldown: Local<f32>,
-    time: Res<Time>,
+    time: Res<timestep::FixedTime>,
 ) {
     *sound_cooldown -= time.delta_seconds();
     *sound_cooldown = sound_cooldown.clamp(-3.0, 3.0);
@@ -123,18 +177,28 @@ fn detect_blade_collisions(
     mut player_blade_event_writer: EventWriter<player::PlayerBladeEvent>, 
     mut enemy_blade_event_writer: EventWriter<enemy::EnemyBladeEvent>,
     mut football_launch_event_writer: EventWriter<football::LaunchFootballEvent>,
-    combine_blades: Query<(&Transform, &CombineBlade, &Aabb, &GlobalTransform), Without<CornStalk>>,
+    mut commentary_event_writer: EventWriter<commentary::CommentaryEvent>,
+    mut combine_blades: Query<(&Transform, &mut CombineBlade, &Aabb, &GlobalTransform), Without<CornStalk>>,
     mut audio: GameAudio,
     mut game_assets: ResMut<GameAssets>,
 ) {
-    for (blade_transform, blade, blade_aabb, blade_global_transform) in &combine_blades {
+    // with several combines something can be in more than one blade at once, only the first counts
+    let mut hit = HashSet::<Entity>::new();
+
+    for (blade_transform, mut blade, blade_aabb, blade_global_transform) in &mut combine_blades {
         let blade_global_matrix = blade_global_transform.compute_matrix();
         let blade_inverse_transform_matrix = blade_global_matrix.inverse();
         let min: Vec3 = blade_aabb.min().into();
         let max: Vec3 = blade_aabb.max().into();
 
+        // near misses are for the blade as a whole, it's a miss once nobody is close any more
+        let mut anyone_close = false;
+        let mut anyone_down = false;
         for (entity, player_transform, player) in &other_entities.p0() {
-            if player.is_dead { continue; }
+            if player.is_dead || hit.contains(&entity) {
+                anyone_down = true;
+                continue;
+            }
             let player_translation = player_transform.translation;
             let player_inverse = blade_inverse_transform_matrix.transform_point3(player_translation);
 
@@ -142,15 +206,26 @@ fn detect_blade_collisions(
                                 && player_inverse.x < max.x
                                 && player_inverse.z > min.z
                                 && player_inverse.z < max.z;
+            let player_close = player_inverse.x > min.x - NEAR_MISS_DISTANCE
+                            && player_inverse.x < max.x + NEAR_MISS_DISTANCE
+                            && player_inverse.z > min.z - NEAR_MISS_DISTANCE
+                            && player_inverse.z < max.z + NEAR_MISS_DISTANCE;
 
             if player_in_hitbox {
+                hit.insert(entity);
+                anyone_down = true;
                 player_blade_event_writer.send(player::PlayerBladeEvent { entity });
                 audio.play_sfx(&game_assets.player_death);
             }
+            anyone_close |= player_close && !player_in_hitbox;
+        }
+        if blade.player_close && !anyone_close && !anyone_down {
+            commentary_event_writer.send(commentary::CommentaryEvent(commentary::Moment::NearMiss));
         }
+        blade.player_close = anyone_close;
 
         for (entity, enemy_transform, enemy) in &other_entities.p1() {
-            if enemy.is_launched {
+            if enemy.state == enemy::EnemyState::Launched || hit.contains(&entity) {
                 continue;
             }
 
@@ -163,13 +238,14 @@ fn detect_blade_collisions(
                                && enemy_inverse.z < max.z;
 
             if enemy_in_hitbox {
+                hit.insert(entity);
                 enemy_blade_event_writer.send(enemy::EnemyBladeEvent { entity });
                 audio.play_sfx(&game_assets.bounce);
             }
         }
 
         for (entity, football_transform, football) in &other_entities.p2() {
-            if !football.has_landed {
+            if !football.has_landed || hit.contains(&entity) {
                 continue;
             }
 
@@ -182,8 +258,10 @@ fn detect_blade_collisions(
                                   && football_inverse.z < max.z;
 
             if football_in_hitbox {
+                hit.insert(entity);
                 commands.entity(entity).despawn_recursive();
                 football_launch_event_writer.send(football::LaunchFootballEvent);
+                commentary_event_writer.send(commentary::CommentaryEvent(commentary::Moment::FootballPopped));
                 audio.play_sfx(&game_assets.football_pop);
             }
         }
@@ -191,73 +269,88 @@ fn detect_blade_collisions(
 }
 
 fn harvest_corn(
-    mut combines: Query<(&mut Combine, &mut Transform), Without<CornStalk>>,
+    mut combines: Query<(Entity, &mut Combine, &mut Transform), Without<CornStalk>>,
     corns: Query<(&CornStalk, &Transform)>,
+    players: Query<&Transform, (With<player::Player>, Without<Combine>, Without<CornStalk>)>,
     game_state: Res<game_state::GameState>,
-    time: Res<Time>,
+    time: Res<timestep::FixedTime>,
     mut cutscene_state: ResMut<cutscene::CutsceneState>,
+    mut game_rng: ResMut<rng::GameRng>,
+    rounds: Res<rounds::Rounds>,
 ) {
-    for (mut combine, mut combine_transform) in &mut combines {
+    let mut lanes = combines.iter()
+                            .map(|(entity, combine, _)| (entity, combine.target_x_coordinate))
+                            .collect::<HashMap::<_, _>>();
+    let mut unharvested_corn = None;
+    let mut round_over = false;
+
+    for (entity, mut combine, mut combine_transform) in &mut combines {
         match combine.heading {
             Heading::Left | Heading::Right => {
                 if (combine_transform.translation.z < -(game_state.maze_size / 2.0) && combine.heading == Heading::Left)
                 || (combine_transform.translation.z > game_state.maze_size / 2.0 && combine.heading == Heading::Right) {
                     combine.current_rotation_time = 0.0;
-                    let unharvested_corn = corns.iter()
-                                                .filter(|(c, _)| !c.is_harvested)
-                                                .collect::<Vec::<_>>();
-                    let mut rng = thread_rng();
-
-                    if unharvested_corn.is_empty() {
+                    let unharvested_corn: &Vec::<Vec3> = unharvested_corn.get_or_insert_with(|| {
+                        corns.iter()
+                             .filter(|(c, _)| !c.is_harvested)
+                             .map(|(_, t)| t.translation)
+                             .collect::<Vec::<_>>()
+                    });
+
+                    // every combine that finishes a pass notices, the cutscene only starts once
+                    if unharvested_corn.is_empty() && !round_over {
+                        round_over = true;
                         println!("no more corn :(");
-                        match game_state.current_round {
-                            1 => cutscene_state.init(cutscene::Cutscene::RoundTwoOver),
-                            2 => cutscene_state.init(cutscene::Cutscene::RoundThreeOver),
-                            _ => cutscene_state.init(cutscene::Cutscene::RoundOneOver),
-                        }
+                        cutscene_state.init(rounds.get(game_state.current_round).outro.clone());
                     }
 
-                    let corn_transform = unharvested_corn.choose(&mut rng).map(|(_, t)| *t);
+                    // corn in lanes other combines have reserved is left to them
+                    let free_corn = unharvested_corn.iter()
+                                                    .filter(|corn| lanes.iter()
+                                                                        .filter(|(other, _)| **other != entity)
+                                                                        .all(|(_, lane)| (corn.x - lane).abs() > LANE_WIDTH))
+                                                    .copied()
+                                                    .collect::<Vec::<_>>();
+
+                    let mut context = HarvestContext {
+                        combine_position: combine_transform.translation,
+                        unharvested: &free_corn,
+                        // the closest runner when there's more than one
+                        player_position: players.iter()
+                                                .map(|t| t.translation)
+                                                .min_by(|a, b| a.distance(combine_transform.translation)
+                                                                .partial_cmp(&b.distance(combine_transform.translation))
+                                                                .unwrap()),
+                        rng: game_rng.stream(rng::RngStream::Combine),
+                    };
                     combine.target_x_coordinate =
-                        if let Some(corn_transform) = corn_transform  {
-                            corn_transform.translation.x
+                        if let Some(lane) = combine.strategy.next_lane(&mut context) {
+                            lane
+                        } else if !unharvested_corn.is_empty() {
+                            // everything left is in someone else's lane, go back over this one
+                            combine_transform.translation.x
                         } else {
                             println!("corn issue??");
                             0.0
                         };
+                    lanes.insert(entity, combine.target_x_coordinate);
 
                     combine.velocity.z = 0.0;
                     if combine.target_x_coordinate > combine_transform.translation.x {
-                        combine.target_rotation = Quat::from_rotation_y(0.0);
-                        combine.heading = Heading::Up;
+                        combine.turn(Heading::Up);
                     } else {
-                        combine.target_rotation = Quat::from_rotation_y(TAU * 0.5); 
-                        combine.heading = Heading::Down;
+                        combine.turn(Heading::Down);
                     }
                 }
             },
             Heading::Up => {
                 if combine_transform.translation.x >= combine.target_x_coordinate {
-                    combine.heading = if combine_transform.translation.z > 0.0 { Heading::Left } else { Heading::Right };
-                    combine.current_rotation_time = 0.0;
-                    combine.velocity.x = 0.0;
-                    combine.target_rotation = match combine.heading {
-                        Heading::Left => Quat::from_rotation_y(TAU * 0.25),
-                        Heading::Right => Quat::from_rotation_y(TAU * 0.75),
-                        _ => combine.target_rotation
-                    };
+                    combine.start_pass(combine_transform.translation);
                 }
             },
             Heading::Down => {
                 if combine_transform.translation.x < combine.target_x_coordinate {
-                    combine.heading = if combine_transform.translation.z > 0.0 { Heading::Left } else { Heading::Right };
-                    combine.current_rotation_time = 0.0;
-                    combine.velocity.x = 0.0;
-                    combine.target_rotation = match combine.heading {
-                        Heading::Left => Quat::from_rotation_y(TAU * 0.25),
-                        Heading::Right => Quat::from_rotation_y(TAU * 0.75),
-                        _ => combine.target_rotation
-                    };
+                    combine.start_pass(combine_transform.translation);
                 }
             },
         }
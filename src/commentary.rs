@@ -0,0 +1,162 @@
+use crate::{
+    assets::GameAssets, enemy, game_state, ingame_ui, player, rng, localization::Localization,
+    timestep::{self, FixedEventAppExt},
+};
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use rand::Rng;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// Bill and Will call what just happened on the field. Each kind of moment has a pool of lines in
+// assets/data/game.commentary.ron along with how much it matters, how long before it can be
+// called again and whether it's worth talking over whatever is already in the textbox.
+
+pub struct CommentaryPlugin;
+impl Plugin for CommentaryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<CommentaryLines>()
+            .init_asset_loader::<CommentaryLinesLoader>()
+            .init_resource::<CommentaryState>()
+            .add_fixed_event::<CommentaryEvent>()
+            .add_system_set_to_stage(
+                timestep::FixedUpdateStage,
+                timestep::gameplay()
+                    .with_system(comment_on_moments)
+            );
+    }
+}
+
+const TEXT_SPEED: f32 = 0.2;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash)]
+pub enum Moment {
+    Touchdown,
+    PlayerBladed,
+    EnemyBladed,
+    Attached,
+    // the player got close to a blade and got away again
+    NearMiss,
+    FootballPopped,
+}
+
+// for moments that don't already have an event of their own,
+// touchdowns and blade hits are picked up from the events gameplay sends anyway
+pub struct CommentaryEvent(pub Moment);
+
+#[derive(Clone, Debug, Deserialize, TypeUuid)]
+#[uuid = "6b2f0d83-4c1a-4e97-a5d8-93e7b1c2f064"]
+pub struct CommentaryLines {
+    pub moments: HashMap::<Moment, MomentLines>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MomentLines {
+    // banter is 0, when two moments land in the same step only the higher one is called
+    pub priority: u32,
+    // cut off anything lower priority that's still being said instead of letting this one go
+    pub interrupts: bool,
+    // seconds before this kind of moment is called again
+    pub cooldown: f32,
+    // so the hosts don't comment on every single one
+    #[serde(default = "always")]
+    pub chance: f32,
+    // each exchange is said all together, one is picked at random
+    pub exchanges: Vec::<Vec::<ingame_ui::HostLine>>,
+}
+
+fn always() -> f32 {
+    1.0
+}
+
+#[derive(Default)]
+pub struct CommentaryState {
+    cooldowns: HashMap::<Moment, f32>,
+    // the exchange used last time for each moment so the same one isn't heard twice in a row
+    last_exchange: HashMap::<Moment, usize>,
+}
+
+fn comment_on_moments(
+    mut commentary_state: ResMut<CommentaryState>,
+    mut commentary_event_reader: EventReader<CommentaryEvent>,
+    mut touchdown_event_reader: EventReader<game_state::TouchdownEvent>,
+    mut player_blade_event_reader: EventReader<player::PlayerBladeEvent>,
+    mut enemy_blade_event_reader: EventReader<enemy::EnemyBladeEvent>,
+    mut textbox_event_writer: EventWriter<ingame_ui::SetTextBoxEvent>,
+    textbox: Res<ingame_ui::TextBox>,
+    commentary: Res<Assets<CommentaryLines>>,
+    game_assets: Res<GameAssets>,
+    mut game_rng: ResMut<rng::GameRng>,
+    time: Res<timestep::FixedTime>,
+    localization: Res<Localization>,
+) {
+    for cooldown in commentary_state.cooldowns.values_mut() {
+        *cooldown -= time.delta_seconds();
+    }
+
+    let moments = commentary_event_reader.iter().map(|event| event.0)
+        .chain(touchdown_event_reader.iter().map(|_| Moment::Touchdown))
+        .chain(player_blade_event_reader.iter().map(|_| Moment::PlayerBladed))
+        .chain(enemy_blade_event_reader.iter().map(|_| Moment::EnemyBladed))
+        .collect::<Vec::<_>>();
+    if moments.is_empty() { return; }
+
+    let commentary = match commentary.get(&game_assets.commentary) {
+        Some(commentary) => commentary,
+        None => return,
+    };
+
+    let called = moments.iter()
+        .filter(|moment| commentary_state.cooldowns.get(*moment).map_or(true, |cooldown| *cooldown <= 0.0))
+        .filter_map(|moment| commentary.moments.get(moment).map(|lines| (*moment, lines)))
+        .filter(|(_, lines)| !lines.exchanges.is_empty())
+        .max_by_key(|(_, lines)| lines.priority);
+    let (moment, lines) = match called {
+        Some(called) => called,
+        None => return,
+    };
+
+    // anything not worth cutting in for is dropped, it'd be old news once the textbox frees up
+    if textbox.is_showing() && !(lines.interrupts && lines.priority > textbox.priority()) {
+        return;
+    }
+
+    // the cooldown starts even if the chance roll fails so a run of them isn't rerolled every step
+    commentary_state.cooldowns.insert(moment, lines.cooldown);
+
+    let rng = game_rng.stream(rng::RngStream::Commentary);
+    if !rng.gen_bool(lines.chance.clamp(0.0, 1.0) as f64) { return; }
+
+    let mut index = rng.gen_range(0..lines.exchanges.len());
+    if lines.exchanges.len() > 1 && commentary_state.last_exchange.get(&moment) == Some(&index) {
+        index = (index + 1) % lines.exchanges.len();
+    }
+    commentary_state.last_exchange.insert(moment, index);
+
+    textbox_event_writer.send(ingame_ui::SetTextBoxEvent {
+        texts: lines.exchanges[index].iter().map(|line| line.to_text(TEXT_SPEED, &game_assets, &localization)).collect(),
+        priority: lines.priority,
+    });
+}
+
+#[derive(Default)]
+pub struct CommentaryLinesLoader;
+
+impl AssetLoader for CommentaryLinesLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let commentary: CommentaryLines = ron::de::from_bytes(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(commentary));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["commentary.ron"]
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{collision, maze, combine, assets::GameAssets, ingame, other_persons, game_state};
+use crate::{collision, maze, combine, assets::GameAssets, ingame, other_persons, game_state, rounds};
 use bevy::prelude::*;
 use bevy::gltf::Gltf;
 use bevy::render::primitives::Aabb;
@@ -40,6 +40,7 @@ fn add_components(
     game_assets: Res<GameAssets>,
     asset_server: Res<AssetServer>,
     assets_gltf: Res<Assets<Gltf>>,
+    rounds: Res<rounds::Rounds>,
 ) {
     if component_adder.has_added {
         return;
@@ -75,7 +76,8 @@ fn add_components(
         }
 
         if name.as_str().contains("maze") {
-            if !game_state.corn_spawned {
+            // generated mazes are spawned in ingame::setup instead
+            if !game_state.corn_spawned && !rounds.get(game_state.current_round).generate_corn {
                 let matrix = global_transform.compute_matrix();
                 commands
                     .entity(entity)
@@ -85,6 +87,7 @@ fn add_components(
                             min: matrix.transform_point3(aabb.min().into()),
                             max: matrix.transform_point3(aabb.max().into()),
                         },
+                        grid: None,
                     });
 
                 println!("found maze");
@@ -97,7 +100,7 @@ fn add_components(
             let matrix = global_transform.compute_matrix();
             commands
                 .entity(entity)
-                .insert(combine::CombineBlade);
+                .insert(combine::CombineBlade::default());
             visibility.is_visible = false;
 
             println!("found combine blade");
@@ -0,0 +1,664 @@
+use crate::{
+    assets::GameAssets, audio::GameAudio, cleanup, game_controller, game_state, menus, options, persistence,
+    player::{Player, PlayerAction}, title_screen::MenuAction, ui::text_size, AppState,
+    localization::{Localization, LocalizedText},
+};
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// Which keys and buttons do what, for the runner and for the menus. They can be changed on the
+// controls screen in the options menu and are kept in controls.ron, an action the file doesn't
+// mention keeps its default. Input maps are always spawned with the defaults and the saved
+// bindings are swapped in before their first update, pointed at each runner's gamepad. How
+// the left stick responds is only set in the file, and so are the second runner's keys for versus.
+
+const CONTROLS_FILE: &str = "controls.ron";
+// how far along an axis the left stick has to be pushed to step through a menu
+const MENU_STICK_THRESHOLD: f32 = 0.5;
+
+pub struct ControlsPlugin;
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Controls::load())
+            .init_resource::<ControlsScreen>()
+            .add_system_to_stage(CoreStage::PostUpdate, apply_controls)
+            .add_system_set(
+                SystemSet::on_enter(AppState::Controls)
+                    .with_system(setup),
+            )
+            .add_system_set(
+                SystemSet::on_update(AppState::Controls)
+                    .with_system(update_controls)
+                    .with_system(display_controls.after(update_controls)),
+            )
+            .add_system_set(
+                SystemSet::on_exit(AppState::Controls)
+                    .with_system(save_controls)
+                    .with_system(cleanup::<CleanupMarker>),
+            );
+    }
+}
+
+const PLAYER_ACTIONS: [(PlayerAction, &str); 9] = [
+    (PlayerAction::Up, "controls.player.up"),
+    (PlayerAction::Down, "controls.player.down"),
+    (PlayerAction::Left, "controls.player.left"),
+    (PlayerAction::Right, "controls.player.right"),
+    (PlayerAction::ActionUp, "controls.player.action_up"),
+    (PlayerAction::ActionDown, "controls.player.action_down"),
+    (PlayerAction::ActionLeft, "controls.player.action_left"),
+    (PlayerAction::ActionRight, "controls.player.action_right"),
+    (PlayerAction::Pause, "controls.player.pause"),
+];
+
+const MENU_ACTIONS: [(MenuAction, &str); 5] = [
+    (MenuAction::Up, "controls.menu.up"),
+    (MenuAction::Down, "controls.menu.down"),
+    (MenuAction::Left, "controls.menu.left"),
+    (MenuAction::Right, "controls.menu.right"),
+    (MenuAction::Select, "controls.menu.select"),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Binding {
+    Key(KeyCode),
+    Button(GamepadButtonType),
+}
+
+impl Binding {
+    fn same_device(&self, other: &Binding) -> bool {
+        matches!((self, other), (Binding::Key(_), Binding::Key(_)) | (Binding::Button(_), Binding::Button(_)))
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Binding::Key(key) => format!("{:?}", key),
+            Binding::Button(button) => format!("{:?}", button),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ActionBindings<A> {
+    pub action: A,
+    pub bindings: Vec::<Binding>,
+}
+
+fn bind<A>(action: A, bindings: &[Binding]) -> ActionBindings<A> {
+    ActionBindings {
+        action,
+        bindings: bindings.to_vec(),
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Controls {
+    pub player: Vec::<ActionBindings<PlayerAction>>,
+    pub menu: Vec::<ActionBindings<MenuAction>>,
+    // the second runner in versus, the first one gives up any of these keys it also has
+    pub player_two: Vec::<ActionBindings<PlayerAction>>,
+    pub stick: StickResponse,
+}
+
+// The left stick moves the runner by how far it's pushed. Anything inside the deadzone is ignored
+// and what's left is stretched back out to 0..1 then raised to the curve, above 1 gives finer
+// control near the middle and below 1 gets to full speed sooner.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct StickResponse {
+    pub deadzone: f32,
+    pub curve: f32,
+}
+
+impl Default for StickResponse {
+    fn default() -> Self {
+        StickResponse {
+            deadzone: 0.2,
+            curve: 1.5,
+        }
+    }
+}
+
+impl StickResponse {
+    // radial so pushing along a diagonal isn't cut off sooner than straight up or across
+    pub fn apply(&self, stick: Vec2) -> Vec2 {
+        let length = stick.length();
+        if length <= self.deadzone || self.deadzone >= 1.0 {
+            return Vec2::ZERO;
+        }
+
+        let amount = ((length - self.deadzone) / (1.0 - self.deadzone)).clamp(0.0, 1.0);
+        (stick / length) * amount.powf(self.curve)
+    }
+
+    // hand edited files can have anything in them
+    fn clamped(self) -> Self {
+        StickResponse {
+            deadzone: self.deadzone.clamp(0.0, 0.9),
+            curve: self.curve.clamp(0.2, 5.0),
+        }
+    }
+}
+
+impl Default for Controls {
+    fn default() -> Self {
+        use Binding::*;
+
+        // Z and Q are there for AZERTY keyboards
+        Controls {
+            player: vec![
+                bind(PlayerAction::Up, &[Key(KeyCode::Up), Key(KeyCode::W), Key(KeyCode::Z), Button(GamepadButtonType::DPadUp)]),
+                bind(PlayerAction::Down, &[Key(KeyCode::Down), Key(KeyCode::S), Button(GamepadButtonType::DPadDown)]),
+                bind(PlayerAction::Left, &[Key(KeyCode::Left), Key(KeyCode::A), Key(KeyCode::Q), Button(GamepadButtonType::DPadLeft)]),
+                bind(PlayerAction::Right, &[Key(KeyCode::Right), Key(KeyCode::D), Button(GamepadButtonType::DPadRight)]),
+                bind(PlayerAction::ActionUp, &[Key(KeyCode::I), Button(GamepadButtonType::North)]),
+                bind(PlayerAction::ActionDown, &[Key(KeyCode::K), Button(GamepadButtonType::South)]),
+                bind(PlayerAction::ActionLeft, &[Key(KeyCode::J), Button(GamepadButtonType::West)]),
+                bind(PlayerAction::ActionRight, &[Key(KeyCode::L), Button(GamepadButtonType::East)]),
+                bind(PlayerAction::Pause, &[Key(KeyCode::Escape), Button(GamepadButtonType::Start)]),
+            ],
+            menu: vec![
+                bind(MenuAction::Up, &[Key(KeyCode::Up), Key(KeyCode::W), Key(KeyCode::Z), Button(GamepadButtonType::DPadUp)]),
+                bind(MenuAction::Down, &[Key(KeyCode::Down), Key(KeyCode::S), Button(GamepadButtonType::DPadDown)]),
+                bind(MenuAction::Left, &[Key(KeyCode::Left), Key(KeyCode::A), Key(KeyCode::Q), Button(GamepadButtonType::DPadLeft)]),
+                bind(MenuAction::Right, &[Key(KeyCode::Right), Key(KeyCode::D), Button(GamepadButtonType::DPadRight)]),
+                bind(MenuAction::Select, &[Key(KeyCode::Space), Key(KeyCode::Return), Button(GamepadButtonType::South), Button(GamepadButtonType::Start)]),
+            ],
+            player_two: vec![
+                bind(PlayerAction::Up, &[Key(KeyCode::Up), Button(GamepadButtonType::DPadUp)]),
+                bind(PlayerAction::Down, &[Key(KeyCode::Down), Button(GamepadButtonType::DPadDown)]),
+                bind(PlayerAction::Left, &[Key(KeyCode::Left), Button(GamepadButtonType::DPadLeft)]),
+                bind(PlayerAction::Right, &[Key(KeyCode::Right), Button(GamepadButtonType::DPadRight)]),
+                bind(PlayerAction::ActionUp, &[Key(KeyCode::Numpad8), Button(GamepadButtonType::North)]),
+                bind(PlayerAction::ActionDown, &[Key(KeyCode::Numpad2), Button(GamepadButtonType::South)]),
+                bind(PlayerAction::ActionLeft, &[Key(KeyCode::Numpad4), Button(GamepadButtonType::West)]),
+                bind(PlayerAction::ActionRight, &[Key(KeyCode::Numpad6), Button(GamepadButtonType::East)]),
+                bind(PlayerAction::Pause, &[Button(GamepadButtonType::Start)]),
+            ],
+            stick: StickResponse::default(),
+        }
+    }
+}
+
+impl Controls {
+    fn load() -> Self {
+        let mut controls = Controls::default();
+        if let Some(saved) = persistence::load::<Controls>(CONTROLS_FILE) {
+            merge(&mut controls.player, saved.player);
+            merge(&mut controls.menu, saved.menu);
+            merge(&mut controls.player_two, saved.player_two);
+            controls.stick = saved.stick.clamped();
+        }
+
+        controls
+    }
+
+    pub fn save(&self) {
+        persistence::save(CONTROLS_FILE, self);
+    }
+
+    pub fn player_input_map(&self, gamepad: Option<Gamepad>) -> InputMap<PlayerAction> {
+        input_map(&self.player, gamepad)
+    }
+
+    // the left stick steps through the menus like the dpad, it can't be rebound so it's added
+    // here rather than kept with the saved bindings
+    pub fn menu_input_map(&self, gamepad: Option<Gamepad>) -> InputMap<MenuAction> {
+        let mut input_map = input_map(&self.menu, gamepad);
+        input_map.insert(SingleAxis::positive_only(GamepadAxisType::LeftStickY, MENU_STICK_THRESHOLD), MenuAction::Up)
+                 .insert(SingleAxis::negative_only(GamepadAxisType::LeftStickY, -MENU_STICK_THRESHOLD), MenuAction::Down)
+                 .insert(SingleAxis::negative_only(GamepadAxisType::LeftStickX, -MENU_STICK_THRESHOLD), MenuAction::Left)
+                 .insert(SingleAxis::positive_only(GamepadAxisType::LeftStickX, MENU_STICK_THRESHOLD), MenuAction::Right);
+        input_map
+    }
+
+    // both runners share the keyboard so the first one's keys are whatever the second isn't using
+    pub fn versus_input_map(&self, number: usize, gamepad: Option<Gamepad>) -> InputMap<PlayerAction> {
+        if number > 0 {
+            return input_map(&self.player_two, gamepad);
+        }
+
+        let taken = self.player_two.iter()
+                                   .flat_map(|action| action.bindings.iter())
+                                   .filter(|binding| matches!(binding, Binding::Key(_)))
+                                   .collect::<Vec::<_>>();
+        let player_one = self.player.iter()
+                                    .map(|action| ActionBindings {
+                                        action: action.action,
+                                        bindings: action.bindings.iter()
+                                                                 .filter(|binding| !taken.contains(binding))
+                                                                 .copied()
+                                                                 .collect(),
+                                    })
+                                    .collect::<Vec::<_>>();
+        input_map(&player_one, gamepad)
+    }
+}
+
+// saved bindings over the defaults, an action saved with nothing bound would be stuck so it isn't taken
+fn merge<A: PartialEq>(defaults: &mut [ActionBindings<A>], saved: Vec::<ActionBindings<A>>) {
+    for saved in saved {
+        if saved.bindings.is_empty() { continue; }
+
+        if let Some(default) = defaults.iter_mut().find(|default| default.action == saved.action) {
+            default.bindings = saved.bindings;
+        }
+    }
+}
+
+fn input_map<A: Actionlike>(actions: &[ActionBindings<A>], gamepad: Option<Gamepad>) -> InputMap<A> {
+    let mut input_map = InputMap::default();
+
+    if let Some(gamepad) = gamepad {
+        input_map.set_gamepad(gamepad);
+    }
+
+    for action in actions {
+        for binding in action.bindings.iter() {
+            match binding {
+                Binding::Key(key) => input_map.insert(*key, action.action.clone()),
+                Binding::Button(button) => input_map.insert(*button, action.action.clone()),
+            };
+        }
+    }
+
+    input_map
+}
+
+// a key replaces the keys an action already had and a button replaces the buttons, so everything
+// can still be done from both. Bindings are only checked against actions in the same group since
+// the menus and the runner are never listening at the same time.
+fn rebind<A: Copy + PartialEq>(actions: &mut [ActionBindings<A>], action: A, binding: Binding) -> Result<(), A> {
+    if let Some(other) = actions.iter().find(|other| other.action != action && other.bindings.contains(&binding)) {
+        return Err(other.action);
+    }
+
+    if let Some(bound) = actions.iter_mut().find(|bound| bound.action == action) {
+        bound.bindings.retain(|existing| !existing.same_device(&binding));
+        bound.bindings.push(binding);
+    }
+
+    Ok(())
+}
+
+// runs after everything spawned this frame is in so nothing reads input with the defaults
+fn apply_controls(
+    controls: Res<Controls>,
+    runner_gamepads: Res<game_controller::RunnerGamepads>,
+    game_state: Res<game_state::GameState>,
+    mut player_maps: Query<(&mut InputMap<PlayerAction>, &Player, ChangeTrackers<InputMap<PlayerAction>>)>,
+    mut menu_maps: Query<(&mut InputMap<MenuAction>, ChangeTrackers<InputMap<MenuAction>>)>,
+) {
+    for (mut input_map, player, trackers) in &mut player_maps {
+        if controls.is_changed() || runner_gamepads.is_changed() || trackers.is_added() {
+            let gamepad = runner_gamepads.runner(player.number, game_state.versus);
+            *input_map = if game_state.versus {
+                controls.versus_input_map(player.number, gamepad)
+            } else {
+                controls.player_input_map(gamepad)
+            };
+        }
+    }
+
+    for (mut input_map, trackers) in &mut menu_maps {
+        if controls.is_changed() || runner_gamepads.is_changed() || trackers.is_added() {
+            *input_map = controls.menu_input_map(runner_gamepads.first());
+        }
+    }
+}
+
+fn save_controls(controls: Res<Controls>) {
+    controls.save();
+}
+
+#[derive(Component)]
+struct CleanupMarker;
+
+#[derive(Component)]
+struct ControlsRow(usize);
+
+#[derive(Component)]
+struct BindingsText(usize);
+
+#[derive(Component)]
+struct MessageMarker;
+
+#[derive(Clone, Copy)]
+enum Row {
+    Player(PlayerAction),
+    Menu(MenuAction),
+    Reset,
+    Back,
+}
+
+fn rows() -> Vec::<(Row, &'static str)> {
+    PLAYER_ACTIONS.iter().map(|(action, key)| (Row::Player(*action), *key))
+        .chain(MENU_ACTIONS.iter().map(|(action, key)| (Row::Menu(*action), *key)))
+        .chain([(Row::Reset, "controls.reset"), (Row::Back, "controls.back")])
+        .collect()
+}
+
+fn label<A: PartialEq>(actions: &[(A, &'static str)], action: A) -> &'static str {
+    actions.iter()
+        .find(|(labelled, _)| *labelled == action)
+        .map_or("", |(_, key)| *key)
+}
+
+#[derive(Default)]
+struct ControlsScreen {
+    selected: usize,
+    // waiting on a new key or button for the selected row
+    capturing: bool,
+    // what to press, or why the last one wasn't taken
+    message: Option::<String>,
+    input_cooldown: f32,
+}
+
+fn setup(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    mut screen: ResMut<ControlsScreen>,
+    app_state: Res<State<AppState>>,
+    text_scaler: text_size::TextScaler,
+    localization: Res<Localization>,
+) {
+    *screen = ControlsScreen {
+        input_cooldown: 0.2,
+        ..Default::default()
+    };
+
+    // over a paused round its camera is still there
+    let over_round = app_state.inactives().contains(&AppState::Pause);
+    if !over_round {
+        commands
+            .spawn_bundle(Camera3dBundle {
+                ..Default::default()
+            })
+            .insert(CleanupMarker);
+    }
+
+    commands
+        .spawn_bundle(InputManagerBundle {
+            input_map: MenuAction::default_input_map(),
+            action_state: ActionState::default(),
+        })
+        .insert(CleanupMarker);
+
+    let font = localization.font(&game_assets);
+    let text_style = TextStyle {
+        font: font.clone(),
+        font_size: text_scaler.scale(menus::FOLLOW_FONT_SIZE),
+        color: Color::WHITE,
+    };
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::ColumnReverse,
+                ..Default::default()
+            },
+            color: if over_round { menus::OVERLAY_BACKGROUND.into() } else { Color::NONE.into() },
+            ..Default::default()
+        })
+        .insert(CleanupMarker)
+        .with_children(|parent| {
+            options::add_title(
+                parent,
+                font.clone(),
+                text_scaler.scale(menus::DEFAULT_FONT_SIZE),
+                &localization.get("controls.title"),
+                vec![LocalizedText("controls.title".to_string())],
+            );
+
+            parent
+                .spawn_bundle(TextBundle {
+                    style: Style {
+                        margin: UiRect {
+                            top: Val::Percent(1.0),
+                            bottom: Val::Percent(1.0),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    text: Text::from_section("".to_string(), text_style.clone()),
+                    ..Default::default()
+                })
+                .insert(MessageMarker);
+
+            for (index, (_, key)) in rows().iter().enumerate() {
+                parent
+                    .spawn_bundle(NodeBundle {
+                        style: Style {
+                            size: Size::new(Val::Percent(70.0), Val::Percent(4.5)),
+                            position_type: PositionType::Relative,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        color: menus::NORMAL_BUTTON.into(),
+                        ..Default::default()
+                    })
+                    .insert(ControlsRow(index))
+                    .with_children(|parent| {
+                        parent
+                            .spawn_bundle(NodeBundle {
+                                style: Style {
+                                    size: Size::new(Val::Percent(40.0), Val::Percent(100.0)),
+                                    align_items: AlignItems::Center,
+                                    ..Default::default()
+                                },
+                                color: Color::NONE.into(),
+                                ..Default::default()
+                            })
+                            .with_children(|parent| {
+                                parent
+                                    .spawn_bundle(TextBundle {
+                                        style: Style {
+                                            margin: UiRect {
+                                                left: Val::Percent(4.0),
+                                                ..Default::default()
+                                            },
+                                            ..Default::default()
+                                        },
+                                        text: Text::from_section(localization.get(key), text_style.clone()),
+                                        ..Default::default()
+                                    })
+                                    .insert(LocalizedText(key.to_string()))
+                                    .insert(ControlsRow(index));
+                            });
+
+                        parent
+                            .spawn_bundle(TextBundle {
+                                text: Text::from_section("".to_string(), text_style.clone()),
+                                ..Default::default()
+                            })
+                            .insert(BindingsText(index))
+                            .insert(ControlsRow(index));
+                    });
+            }
+        });
+}
+
+fn update_controls(
+    mut screen: ResMut<ControlsScreen>,
+    mut controls: ResMut<Controls>,
+    action_state: Query<&ActionState<MenuAction>>,
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<GamepadButton>>,
+    runner_gamepads: Res<game_controller::RunnerGamepads>,
+    mut app_state: ResMut<State<AppState>>,
+    localization: Res<Localization>,
+    game_assets: Res<GameAssets>,
+    mut audio: GameAudio,
+    time: Res<Time>,
+) {
+    // only touched while counting down so the rows aren't redrawn every frame
+    if screen.input_cooldown > 0.0 {
+        screen.input_cooldown -= time.delta_seconds();
+        return;
+    }
+
+    let rows = rows();
+    let (row, key) = rows[screen.selected];
+
+    if screen.capturing {
+        // Escape can still be bound by resetting to the defaults
+        if keys.just_pressed(KeyCode::Escape) {
+            screen.capturing = false;
+            screen.message = None;
+            return;
+        }
+
+        let binding = keys.get_just_pressed().next().map(|key| Binding::Key(*key))
+            .or_else(|| buttons.get_just_pressed()
+                               .find(|button| Some(button.gamepad) == runner_gamepads.first())
+                               .map(|button| Binding::Button(button.button_type)));
+        let binding = match binding {
+            Some(binding) => binding,
+            None => return,
+        };
+
+        let conflict = match row {
+            Row::Player(action) => rebind(&mut controls.player, action, binding)
+                .map_err(|other| label(&PLAYER_ACTIONS, other)),
+            Row::Menu(action) => rebind(&mut controls.menu, action, binding)
+                .map_err(|other| label(&MENU_ACTIONS, other)),
+            _ => Ok(()),
+        };
+
+        audio.play_sfx(&game_assets.blip);
+        match conflict {
+            Ok(()) => {
+                screen.capturing = false;
+                screen.message = None;
+                // the binding might be the menu's select, it shouldn't go straight into another capture
+                screen.input_cooldown = 0.2;
+            },
+            // stays waiting so something else can be picked
+            Err(other) => {
+                screen.message = Some(localization.get("controls.conflict")
+                                                  .replace("{binding}", &binding.name())
+                                                  .replace("{action}", &localization.get(other)));
+            },
+        }
+        return;
+    }
+
+    let action_state = match action_state.get_single() {
+        Ok(action_state) => action_state,
+        Err(_) => return,
+    };
+
+    if action_state.just_pressed(MenuAction::Up) {
+        audio.play_sfx(&game_assets.blip);
+        screen.selected = screen.selected.checked_sub(1).unwrap_or(rows.len() - 1);
+        screen.message = None;
+    }
+    if action_state.just_pressed(MenuAction::Down) {
+        audio.play_sfx(&game_assets.blip);
+        screen.selected = (screen.selected + 1) % rows.len();
+        screen.message = None;
+    }
+    if action_state.just_pressed(MenuAction::Select) {
+        audio.play_sfx(&game_assets.blip);
+        match row {
+            Row::Player(_) | Row::Menu(_) => {
+                screen.capturing = true;
+                screen.input_cooldown = 0.2;
+                screen.message = Some(localization.get("controls.press").replace("{action}", &localization.get(key)));
+            },
+            Row::Reset => {
+                *controls = Controls::default();
+            },
+            Row::Back => {
+                app_state.pop().unwrap();
+            },
+        }
+    }
+}
+
+fn display_controls(
+    screen: Res<ControlsScreen>,
+    controls: Res<Controls>,
+    localization: Res<Localization>,
+    mut rows: Query<(&ControlsRow, &mut UiColor)>,
+    mut texts: Query<(&ControlsRow, &mut Text, Option<&BindingsText>), Without<MessageMarker>>,
+    mut message: Query<&mut Text, With<MessageMarker>>,
+) {
+    if !screen.is_changed() && !controls.is_changed() && !localization.is_changed() { return; }
+
+    let all_rows = rows();
+    for (row, mut color) in &mut rows {
+        *color = if row.0 == screen.selected { menus::HOVERED_BUTTON.into() } else { menus::NORMAL_BUTTON.into() };
+    }
+
+    for (row, mut text, bindings) in &mut texts {
+        text.sections[0].style.color = if row.0 == screen.selected { Color::BLACK } else { Color::WHITE };
+
+        if let Some(bindings) = bindings {
+            let bound = match all_rows[bindings.0].0 {
+                Row::Player(action) => controls.player.iter().find(|bound| bound.action == action).map(|bound| &bound.bindings),
+                Row::Menu(action) => controls.menu.iter().find(|bound| bound.action == action).map(|bound| &bound.bindings),
+                _ => None,
+            };
+
+            text.sections[0].value = if screen.capturing && bindings.0 == screen.selected {
+                "...".to_string()
+            } else {
+                bound.map_or(String::new(), |bound| bound.iter().map(Binding::name).collect::<Vec::<_>>().join(", "))
+            };
+        }
+    }
+
+    for mut text in &mut message {
+        text.sections[0].value = screen.message.clone().unwrap_or_else(|| localization.get("controls.hint"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inside_the_deadzone_does_nothing() {
+        let stick = StickResponse::default();
+        assert_eq!(stick.apply(Vec2::ZERO), Vec2::ZERO);
+        assert_eq!(stick.apply(Vec2::new(0.2, 0.0)), Vec2::ZERO);
+        assert_eq!(stick.apply(Vec2::new(0.1, -0.1)), Vec2::ZERO);
+    }
+
+    #[test]
+    fn diagonals_are_cut_off_no_sooner_than_cardinals() {
+        let stick = StickResponse::default();
+        // 0.25 each way is further out than the deadzone even though neither axis is
+        let diagonal = stick.apply(Vec2::new(0.25, 0.25));
+        let cardinal = stick.apply(Vec2::new(Vec2::new(0.25, 0.25).length(), 0.0));
+        assert!(diagonal.length() > 0.0);
+        assert!((diagonal.length() - cardinal.length()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn full_deflection_is_full_speed() {
+        let stick = StickResponse::default();
+        for full in [Vec2::X, -Vec2::Y, Vec2::new(1.0, 1.0).normalize(), Vec2::new(1.0, 1.0)] {
+            assert!((stick.apply(full).length() - 1.0).abs() < 1e-5, "{:?}", full);
+        }
+    }
+
+    #[test]
+    fn pushing_further_never_slows_down() {
+        for stick in [StickResponse::default(), StickResponse { deadzone: 0.0, curve: 0.5 }, StickResponse { deadzone: 0.5, curve: 3.0 }] {
+            let mut last = 0.0;
+            for step in 0..=100 {
+                let amount = stick.apply(Vec2::new(0.0, step as f32 / 100.0)).length();
+                assert!(amount >= last, "{:?} at {}", stick, step);
+                last = amount;
+            }
+        }
+    }
+}
@@ -1,11 +1,13 @@
 use crate::{
     assets::GameAssets, cleanup, game_state, menus, AppState, ui::text_size, ingame, other_persons,
-    component_adder::AnimationLink, game_camera, ingame_ui, title_screen::MenuAction, LEFT_GOAL, football,
-    asset_loading, audio::GameAudio, 
+    component_adder::AnimationLink, game_camera, ingame_ui, title_screen::MenuAction, LEFT_GOAL,
+    asset_loading, audio::GameAudio, rng, rounds, localization::Localization,
+    cutscene_script::{CutsceneScripts, CutsceneStep, LoadTarget, DEFAULT_CAMERA_SPEED},
 };
 use std::mem;
 use bevy::prelude::*;
 use rand::Rng;
+use serde::Deserialize;
 use leafwing_input_manager::prelude::ActionState;
 use leafwing_input_manager::InputManagerBundle;
 
@@ -49,6 +51,8 @@ pub struct CutsceneState {
     target_camera_translation: Option::<Vec3>,
     target_camera_rotation: Option::<Quat>,
     camera_speed: f32,
+    wait_time: f32,
+    skipping: bool,
     current_bill_animation: Handle<AnimationClip>,
     current_will_animation: Handle<AnimationClip>,
 }
@@ -62,29 +66,60 @@ impl CutsceneState {
         self.target_camera_translation = None;
         self.target_camera_rotation = None;
         self.waiting_on_input = false;
+        self.camera_speed = DEFAULT_CAMERA_SPEED;
+        self.wait_time = 0.0;
+        self.skipping = false;
         self.current_bill_animation = Handle::<AnimationClip>::default();
         self.current_will_animation = Handle::<AnimationClip>::default();
     }
+
+    // runs the rest of the script without stopping for dialogue or waits
+    pub fn skip(&mut self) {
+        self.skipping = true;
+        self.waiting_on_input = false;
+    }
 }
 
-#[derive(Copy, Clone)]
-pub enum Cutscene {
-    Intro,
-    Death,
-    Tackle,
-    LevelTwoIntro,
-    LevelThreeIntro,
-    RoundOneOver,
-    RoundTwoOver,
-    RoundThreeOver,
+// The name of a script in assets/data/game.cutscenes.ron
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct Cutscene(pub String);
+
+impl Cutscene {
+    pub fn new(name: &str) -> Self {
+        Cutscene(name.to_string())
+    }
+
+    // death_1, death_2 and so on, once there's no script for the count it's just death
+    pub fn death(death_count: usize) -> Self {
+        Cutscene(format!("death_{}", death_count))
+    }
 }
 
 impl Default for Cutscene {
     fn default() -> Self {
-        Cutscene::Intro
+        Cutscene::new("intro")
     }
 }
 
+// on to the next round, or to the high score table after the last one
+fn finish_round(
+    rounds: &rounds::Rounds,
+    assets_handler: &mut asset_loading::AssetsHandler,
+    game_assets: &mut ResMut<GameAssets>,
+    game_state: &mut ResMut<game_state::GameState>,
+) {
+    if rounds.is_last(game_state.current_round) {
+        // the game state is kept until the table has had a look at the run
+        game_state.bank_round();
+        assets_handler.load(AppState::HighScores, game_assets, game_state);
+    } else {
+        assets_handler.load(AppState::LevelOver, game_assets, game_state);
+    }
+}
+
+const TEXT_SPEED: f32 = 0.10;
+
 #[derive(Component)]
 pub struct CutsceneTextBoxContainer;
 #[derive(Component)]
@@ -97,12 +132,15 @@ fn play_cutscene(
     mut assets_handler: asset_loading::AssetsHandler,
     mut game_assets: ResMut<GameAssets>,
     mut game_state: ResMut<game_state::GameState>,
-    mut will_animation_link: Query<&AnimationLink, With<other_persons::WillPerson>>,
-    mut bill_animation_link: Query<&AnimationLink, With<other_persons::BillPerson>>,
+    will_animation_link: Query<&AnimationLink, With<other_persons::WillPerson>>,
+    bill_animation_link: Query<&AnimationLink, With<other_persons::BillPerson>>,
     mut animations: Query<&mut AnimationPlayer>,
-    mut football_launch_event_writer: EventWriter<football::LaunchFootballEvent>,
     mut ingame_ui_textbox: ResMut<ingame_ui::TextBox>,
     mut audio: GameAudio,
+    rounds: Res<rounds::Rounds>,
+    scripts: Res<Assets<CutsceneScripts>>,
+    localization: Res<Localization>,
+    time: Res<Time>,
 ) {
     if let Ok(will_link_check) = will_animation_link.get_single() {
         if will_link_check.entity.is_none() {
@@ -122,1541 +160,104 @@ fn play_cutscene(
     let mut camera = camera.single_mut();
 //    println!("{:?} {:?}", camera.translation, camera.rotation.to_axis_angle());
     if cutscene_state.waiting_on_input { return; }
+    if cutscene_state.wait_time > 0.0 && !cutscene_state.skipping {
+        cutscene_state.wait_time -= time.delta_seconds();
+        return;
+    }
     let mut bill_animation = None;
     let mut will_animation = None;
 
-    cutscene_state.camera_speed = 2.0;
-    cutscene_state.waiting_on_input = true;
-    let text_speed = 0.10;
-
-    if let Some(current) = cutscene_state.current {
+    if let Some(current) = cutscene_state.current.clone() {
         *ingame_ui_textbox = ingame_ui::TextBox::default(); // clear out any banter or commentary
-        match current {
-            Cutscene::LevelTwoIntro => {
-                match cutscene_state.cutscene_index {
-                    0 => {
-                        camera.translation = Vec3::new(22.5, 1.5, 0.0);
-                        camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                        audio.stop_bgm();
-                        cutscene_state.target_camera_translation = Some((Vec3::new(19.3, 1.5, 0.0)));
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Welcome back!".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_right.clone()); 
-                    },
-                    1 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "The field is ready for the next round and so are we!".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
 
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_right.clone()); 
-                    },
-                    2 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "FOUR HOURS".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    3 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Yeah, we ran into some difficulties.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_left_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    4 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "But, we're ready now. We have popcorn. Let's get started!".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    _ => {
-                        camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                                       game_camera::INGAME_CAMERA_Y, 
-                                                       LEFT_GOAL);
-                        camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                                    game_camera::INGAME_CAMERA_ROTATION_ANGLE);
-                        audio.play_bgm(&game_assets.bgm);
-                        game_state.corn_spawned = true;
-                        cutscene_state.current = None;
-                        assets_handler.load(AppState::ResetInGame, &mut game_assets, &game_state);
-                    }
-                }
-            },
-            Cutscene::LevelThreeIntro => {
-                match cutscene_state.cutscene_index {
-                    0 => {
-                        camera.translation = Vec3::new(22.5, 1.5, 0.0);
-                        camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                        audio.stop_bgm();
-                        cutscene_state.target_camera_translation = Some((Vec3::new(19.3, 1.5, 0.0)));
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "And we're back!".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    1 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "As you can tell, it's quite late.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    2 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "We are keeping warm while eating our elotes.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    3 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "I'm having esquites!".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    4 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "That's the same thing".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    5 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "it really isn't.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    6 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Anyway!".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    7 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "We've been cited by the city for the stadium lights being lit after midnight.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_right.clone()); 
-                    },
-                    8 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "We've made an agreement that if we're quiet, we can use two flood lights.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    9 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Players will have to navigate the maze in the dark.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    10 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "They'll be illuminated only by the lights tracking them.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    11 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Dealing with these last-minute adjustments is part of the challenge!".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    12 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Will, what do you think?".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    13 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "I can't see anything.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_talk.clone()); 
-                    },
-                    14 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Fantastic! Let's begin!".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    _ => {
-                        camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                                       game_camera::INGAME_CAMERA_Y, 
-                                                       LEFT_GOAL);
-                        camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                                    game_camera::INGAME_CAMERA_ROTATION_ANGLE);
-                        audio.play_bgm(&game_assets.bgm);
-                        game_state.corn_spawned = true;
-                        cutscene_state.current = None;
-                        assets_handler.load(AppState::ResetInGame, &mut game_assets, &game_state);
-                    }
-                }
-            },
-            Cutscene::Intro => {
-                match cutscene_state.cutscene_index {
-                    0 => {
-                        camera.translation = Vec3::new(22.5, 1.5, 0.0);
-                        camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                        cutscene_state.target_camera_translation = Some((Vec3::new(19.3, 1.5, 0.0)));
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Hello! I'm Bill.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    1 => {
-                        cutscene_state.target_camera_translation = None;
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "and I'm Will!".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_idle.clone()); 
-                        will_animation = Some(game_assets.host_talk.clone());
-                    },
-                    2 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "and we're here live from the USAFL Scouting Combine XLV in Indianapolis!".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    3 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "it's very exciting!".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    4 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "I've been looking forward to this event all year.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    5 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "ha ha ha, ..yeah.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    6 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "well, if you're just joining in at home and have no idea what's going on..".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_talk.clone()); 
-                    },
-                    7 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "The USAFL Scouting Combine is an annual, week-long showcase where athletes perform mental and physical trials to potentially be drafted on an USAFL team.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_talk.clone()); 
-                    },
-                    8 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "We're already a couple days in and most of the crowd-favorite events have passed, but everyone's pumped for this year's new challenge.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_idle.clone()); 
-                        will_animation = Some(game_assets.host_talk.clone()); 
-                    },
-                    9 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "The Combine Combine Challenge!".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_idle.clone()); 
-                        will_animation = Some(game_assets.host_talk.clone()); 
-                    },
-                    10 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "It's very exciting.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    11 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "very exciting".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    12 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Bill, I lost my notes, can you tell our viewers what it's all about?".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    13 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Ha ha, that keeps happening why is that? ha ha".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    14 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "The challenge is for players to score as many touchdowns as they can while navigating a corn maze".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    15 => {
-                        cutscene_state.target_camera_translation = Some((Vec3::new(-18.6, 6.5, 16.041729)));
-                        cutscene_state.target_camera_rotation = Some(
-                            Quat::from_axis_angle(Vec3::new(-0.5818577, -0.7968926, -0.1624936), 0.6599797));
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "As you can see the field is set.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    16 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "We've been generously donated 1,000 acres worth of corn.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    17 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "And between attempts we have a team of 200 volunteers meticulously re-constructing the mazes.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    18 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "This ensures each player has the same exact maze so there aren't any unfair advantages.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    19 => {
-                        camera.translation = Vec3::new(19.3, 1.5, 0.0);
-                        camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-
-                        cutscene_state.target_camera_translation = None;
-                        cutscene_state.target_camera_rotation = None;
-
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "That seems excessive.. that's like a lot of corn.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    20 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Ha ha.. yeah".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    21 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Let's talk more about how this works".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_right.clone()); 
-                    },
-                    22 => {
-                        cutscene_state.target_camera_translation = Some(Vec3::new(-13.1, 2.5, -40.6));
-                        cutscene_state.target_camera_rotation = Some(
-                            Quat::from_axis_angle(Vec3::new(-0.07643463, -0.9914023, -0.10620499), 1.8807149));
-
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Players will start on one side of the field and a kicker will launch a ball into the maze.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_right.clone()); 
-                    },
-                    23 => {
-                        cutscene_state.target_camera_translation = Some(Vec3::new(-11.3, 7.9, 14.1));
-                        cutscene_state.target_camera_rotation = Some(
-                            Quat::from_axis_angle(Vec3::new(-0.50110954, -0.84660023, -0.17932819), 0.78708464));
-
-                        cutscene_state.camera_speed = 0.2;
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "The player will navigate the maze, find the ball and score a point on the other side.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_right.clone()); 
-                    },
-                    24 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Once they score, a ball will be launched from the opposite side into the maze.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_right.clone()); 
-                    },
-                    25 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "And the player will have to turn back, find the ball and score a touchdown on the opposite side.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_right.clone()); 
-                    },
-                    26 => {
-                        camera.translation = Vec3::new(19.3, 1.5, 0.0);
-                        camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                        cutscene_state.camera_speed = 2.0;
-                        cutscene_state.target_camera_translation = None;
-                        cutscene_state.target_camera_rotation = None;
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "And the cycle just repeats from there.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    27 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "That's a lot of running.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    28 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Yeah..".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    29 => {
-                        camera.translation = Vec3::new(-11.3, 7.9, 14.1);
-                        cutscene_state.target_camera_translation = Some(Vec3::new(-11.3, 7.9, -14.1));
-                        camera.rotation = Quat::from_axis_angle(Vec3::new(-0.50110954, -0.84660023, -0.17932819), 0.78708464);
-                        cutscene_state.camera_speed = 0.2;
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "In the maze, we have seasoned USAFL players ready to chase and tackle the player.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    30 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Players won't be able to run as fast if they have one of these professionals holding them down.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    31 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "And if they get tackled, they'll have to go back to the goal line.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    32 => {
-                        camera.translation = Vec3::new(19.3, 1.5, 0.0);
-                        camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                        cutscene_state.target_camera_translation = None;
-                        cutscene_state.target_camera_rotation = None;
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "I think that about covers everything.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    33 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "What about the combine?".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    34 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Oh! Right! The combine!".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    35 => {
-                        cutscene_state.target_camera_translation = Some(Vec3::new(-3.0, 6.5, 28.7));
-                        cutscene_state.target_camera_rotation = Some(
-                            Quat::from_axis_angle(Vec3::new(-0.09976758, -0.9702991, -0.22037746), 2.3035543));
-
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "While the player is attempting to score, a combine will be harvesting the maze.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    36 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Don't worry though, we have a professional driver in the combine.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    37 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Players are equipped with special padding that can't be sliced by the combine's blades. They should just safely bounce away.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    38 => {
-                        camera.translation = Vec3::new(19.3, 1.5, 0.0);
-                        camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                        cutscene_state.target_camera_translation = None;
-                        cutscene_state.target_camera_rotation = None;
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Bill, this seems really dangerous.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    39 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "We have an ambulance on site. And the players have signed waivers.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    40 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "...".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::None,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    41 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "The round ends once the combine has completely harvested the maze.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_right.clone()); 
-                    },
-                    42 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Then, we'll have our team re-plant the maze for the next round.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    43 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "They just... plant that right into the astroturf?".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    44 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Yeah.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    45 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "We know we have players with different preferences on how to compete.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    46 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Players can use the WASD keys, the Arrow keys or ZQSD keys to navigate the maze.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    47 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "...".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::None,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    48 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Bill.. what are you talking about?".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    49 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Ha ha, Will, football is played a little different these days.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    50 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "I think that about covers everything though, right?".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    51 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Actually, this always bugged me.. why is it called \"Combine\"?".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    52 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "OH, that's just because there used to be several scouting showcases across the country.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    53 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "and then one year they decided to combine them into one.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    54 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "...and so instead of calling it the \"Scouting Showcase\", they called it.. \"Combine\"?".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    55 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "ha... yeah.. I uhh.. that's what it's called, man, I don't know.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    56 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Oh, the kicker is ready, I think it's about to begin. Let's watch!".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    _ => {
-                        camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                                       game_camera::INGAME_CAMERA_Y, 
-                                                       LEFT_GOAL);
-                        camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                                    game_camera::INGAME_CAMERA_ROTATION_ANGLE);
-                        game_state.corn_spawned = true;
-                        cutscene_state.current = None;
-                        assets_handler.load(AppState::ResetInGame, &mut game_assets, &game_state);
-                        audio.play_bgm(&game_assets.bgm);
-                    }
-                }
-            },
-            Cutscene::Tackle => {
-                match cutscene_state.cutscene_index {
-                    0 => {
-                        camera.translation = Vec3::new(19.3, 1.5, 0.0);
-                        camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                        cutscene_state.target_camera_translation = None;
-                        cutscene_state.target_camera_rotation = None;
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Oooph, that's a tackle! Back to the goal line.".to_string(),
-                            speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    _ => {
-                        camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                                       game_camera::INGAME_CAMERA_Y, 
-                                                       LEFT_GOAL);
-                        camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                                    game_camera::INGAME_CAMERA_ROTATION_ANGLE);
-                        cutscene_state.current = None;
-                        assets_handler.load(AppState::ResetInGame, &mut game_assets, &game_state);
-                    }
-                }
-            },
-            Cutscene::RoundOneOver => {
-                match cutscene_state.cutscene_index {
-                    0 => {
-                        camera.translation = Vec3::new(19.3, 1.5, 0.0);
-                        camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                        audio.stop_bgm();
-                        cutscene_state.target_camera_translation = None;
-                        cutscene_state.target_camera_rotation = None;
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Well! That's it for round one!".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    1 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "That was a great performance.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-                        bill_animation = Some(game_assets.host_idle.clone()); 
-                        will_animation = Some(game_assets.host_talk.clone()); 
-                    },
-                    2 => {
-                        if game_state.score == 0 {
-                            textbox.queued_text = Some(TextBoxText {
-                                text: "Yeah.. no touch downs though..".to_string(),
-                                speed: text_speed,
-                                auto: false,
-                                speaking: DisplayCharacter::Bill,
-                            });
-                        } else if game_state.score == 100 {
-                            textbox.queued_text = Some(TextBoxText {
-                                text: "It was only one, but it was a great touchdown.".to_string(),
-                                speed: text_speed,
-                                auto: false,
-                                speaking: DisplayCharacter::Bill,
-                            });
-                        } else {
-                            textbox.queued_text = Some(TextBoxText {
-                                text: "Always great to see a couple touchdowns.".to_string(),
-                                speed: text_speed,
-                                auto: false,
-                                speaking: DisplayCharacter::Bill,
-                            });
-                        }
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    3 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "The volunteers are taking to the field now to re-plant.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    4 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Hey uh... how long until the field is ready?".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    5 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "... should only take a couple hours tops.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    6 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "WHAT".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    _ => {
-                        camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                                       game_camera::INGAME_CAMERA_Y, 
-                                                       LEFT_GOAL);
-                        camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                                    game_camera::INGAME_CAMERA_ROTATION_ANGLE);
-                        cutscene_state.current = None;
-                        assets_handler.load(AppState::LevelOver, &mut game_assets, &game_state);
-                    }
-                }
-            },
-            Cutscene::RoundTwoOver => {
-                match cutscene_state.cutscene_index {
-                    0 => {
-                        camera.translation = Vec3::new(19.3, 1.5, 0.0);
-                        camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                        audio.stop_bgm();
-                        cutscene_state.target_camera_translation = None;
-                        cutscene_state.target_camera_rotation = None;
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Wooooo! This is how football was always meant to be!".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    1 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Yeah, I have to admit, that was very exciting.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    2 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Very exciting.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    3 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Well uhh.. I guess we have to replant now..".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    4 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Yeah...".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
+        let steps = scripts.get(&game_assets.cutscenes).and_then(|scripts| scripts.get(&current));
+        if steps.is_none() {
+            println!("no script for cutscene {}", current.0);
+        }
 
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    5 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "We'll be back after the break!".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
+        // scripts should end by loading something, this is what happens if one doesn't
+        let end_of_script = CutsceneStep::Load(LoadTarget::ResumeRound);
 
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    _ => {
-                        camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                                       game_camera::INGAME_CAMERA_Y, 
-                                                       LEFT_GOAL);
-                        camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                                    game_camera::INGAME_CAMERA_ROTATION_ANGLE);
-                        cutscene_state.current = None;
-                        assets_handler.load(AppState::LevelOver, &mut game_assets, &game_state);
-                    }
+        // run steps until one of them has to wait
+        loop {
+            let step = match steps.and_then(|steps| steps.get(cutscene_state.cutscene_index)) {
+                Some(step) => step,
+                None => {
+                    set_ingame_camera(&mut camera);
+                    &end_of_script
                 }
-            },
-            Cutscene::RoundThreeOver => {
-                match cutscene_state.cutscene_index {
-                    0 => {
-                        camera.translation = Vec3::new(19.3, 1.5, 0.0);
-                        camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                        audio.stop_bgm();
-                        cutscene_state.target_camera_translation = None;
-                        cutscene_state.target_camera_rotation = None;
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "What time is it?".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    1 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "It's 3:37am".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    2 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Are these taking longer? We got through three rounds today.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    3 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Yeah, we had to send all the other players home early.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    4 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "The scouts didn't even stay for this last round.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    5 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "I.. I don't think this is the best event for the Combine.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    6 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "No.. it didn't go quite as planned.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    7 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "We did get a lot of corn out of it though.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    8 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "...".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::None,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    9 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "I like corn, Will.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    10 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "I know.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                    },
-                    11 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "...".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::None,
-                        });
-
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_look_left.clone()); 
-                    },
-                    12 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Anyway! If you're still out there, thanks for sticking around.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
-
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    13 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "We're going to have some internal meetings to tweak this event.".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
+            };
+            cutscene_state.cutscene_index += 1;
 
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    14 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "Join us again next time for more USA Football League Scouting Combine coverage!".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Bill,
-                        });
+            let step = match step.resolve(game_state.score) {
+                Some(step) => step,
+                None => continue,
+            };
 
-                        bill_animation = Some(game_assets.host_talk.clone()); 
-                        will_animation = Some(game_assets.host_idle.clone()); 
-                    },
-                    15 => {
-                        textbox.queued_text = Some(TextBoxText {
-                            text: "What a mess".to_string(),
-                            speed: text_speed,
-                            auto: false,
-                            speaking: DisplayCharacter::Will,
-                        });
+            match step {
+                CutsceneStep::Say(speaking, key) => {
+                    if cutscene_state.skipping { continue; }
 
-                        bill_animation = Some(game_assets.host_look_right.clone()); 
-                        will_animation = Some(game_assets.host_talk.clone()); 
-                    },
-                    _ => {
-                        cutscene_state.current = None;
-                        *game_state = game_state::GameState::default();
-                        assets_handler.load(AppState::TitleScreen, &mut game_assets, &game_state);
+                    textbox.queued_text = Some(TextBoxText {
+                        text: localization.get(key),
+                        speed: TEXT_SPEED,
+                        auto: false,
+                        speaking: *speaking,
+                    });
+                    cutscene_state.waiting_on_input = true;
+                    break;
+                },
+                CutsceneStep::ByScore(_) => (),
+                CutsceneStep::Animate { bill, will } => {
+                    bill_animation = Some(bill.clip(&game_assets));
+                    will_animation = Some(will.clip(&game_assets));
+                },
+                CutsceneStep::CutCamera { translation, rotation } => {
+                    camera.translation = Vec3::from(*translation);
+                    camera.rotation = Quat::from_axis_angle(Vec3::from(rotation.0), rotation.1);
+                    cutscene_state.target_camera_translation = None;
+                    cutscene_state.target_camera_rotation = None;
+                },
+                CutsceneStep::MoveCamera { translation, rotation, speed } => {
+                    if let Some(translation) = translation {
+                        cutscene_state.target_camera_translation = Some(Vec3::from(*translation));
                     }
-                }
-            },
-            Cutscene::Death => {
-                match game_state.death_count {
-                    1 => {
-                        match cutscene_state.cutscene_index {
-                            0 => {
-                                camera.translation = Vec3::new(19.3, 1.5, 0.0);
-                                camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                                audio.stop_bgm();
-                                cutscene_state.target_camera_translation = None;
-                                cutscene_state.target_camera_rotation = None;
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "Oh geez! The padding failed! What happened!?".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                                });
-                                bill_animation = Some(game_assets.host_talk.clone()); 
-                                will_animation = Some(game_assets.host_idle.clone()); 
-                            },
-                            1 => {
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "Someone call the medics! ".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                                });
-                                bill_animation = Some(game_assets.host_idle.clone()); 
-                                will_animation = Some(game_assets.host_talk.clone()); 
-                            },
-                            2 => {
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "I think... I think they're getting out...".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                                });
-                                bill_animation = Some(game_assets.host_talk.clone()); 
-                                will_animation = Some(game_assets.host_idle.clone()); 
-                            },
-                            3 => {
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "...".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::None,
-                                });
-                                bill_animation = Some(game_assets.host_idle.clone()); 
-                                will_animation = Some(game_assets.host_look_left.clone()); 
-                            },
-                            4 => {
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "it.. it looks like they're ok?".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                                });
-                                bill_animation = Some(game_assets.host_talk.clone()); 
-                                will_animation = Some(game_assets.host_idle.clone()); 
-                            },
-                            5 => {
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "They're standing up.. they just gave a thumbs-up.".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                                });
-                                bill_animation = Some(game_assets.host_talk.clone()); 
-                                will_animation = Some(game_assets.host_idle.clone()); 
-                            },
-                            6 => {
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "I guess.. I guess we can just continue from here?".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                                });
-                                bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                                will_animation = Some(game_assets.host_look_left.clone()); 
-                            },
-                            _ => {
-                                camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                                               game_camera::INGAME_CAMERA_Y, 
-                                                               LEFT_GOAL);
-                                camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                                            game_camera::INGAME_CAMERA_ROTATION_ANGLE);
-                                audio.play_bgm(&game_assets.bgm);
-                                cutscene_state.current = None;
-                                assets_handler.load(AppState::ResetInGame, &mut game_assets, &game_state);
-                            }
-                        }
-                    },
-                    2 => {
-                        match cutscene_state.cutscene_index {
-                            0 => {
-                                camera.translation = Vec3::new(19.3, 1.5, 0.0);
-                                camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                                audio.stop_bgm();
-                                cutscene_state.target_camera_translation = None;
-                                cutscene_state.target_camera_rotation = None;
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "OH NO NOT AGAIN!".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                                });
-                                bill_animation = Some(game_assets.host_talk.clone()); 
-                                will_animation = Some(game_assets.host_idle.clone()); 
-                            },
-                            1 => {
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "You said the driver was a professional!".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                                });
-                                bill_animation = Some(game_assets.host_idle.clone()); 
-                                will_animation = Some(game_assets.host_talk.clone()); 
-                            },
-                            2 => {
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "...".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::None,
-                                });
-                                bill_animation = Some(game_assets.host_idle.clone()); 
-                                will_animation = Some(game_assets.host_idle.clone()); 
-                            },
-                            3 => {
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "Wow, they're ok. I can't believe it.".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                                });
-                                bill_animation = Some(game_assets.host_idle.clone()); 
-                                will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                            },
-                            4 => {
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "That was worst than the first time.".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                                });
-                                bill_animation = Some(game_assets.host_look_right.clone()); 
-                                will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                            },
-                            5 => {
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "Well.. ok, let's keep going.".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Bill,
-                                });
-                                bill_animation = Some(game_assets.host_look_right_talk.clone()); 
-                                will_animation = Some(game_assets.host_look_left.clone()); 
-                            },
-                            _ => {
-                                camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                                               game_camera::INGAME_CAMERA_Y, 
-                                                               LEFT_GOAL);
-                                camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                                            game_camera::INGAME_CAMERA_ROTATION_ANGLE);
-                                audio.play_bgm(&game_assets.bgm);
-                                cutscene_state.current = None;
-                                assets_handler.load(AppState::ResetInGame, &mut game_assets, &game_state);
-                            }
-                        }
-                    },
-                    3 => {
-                        match cutscene_state.cutscene_index {
-                            0 => {
-                                camera.translation = Vec3::new(19.3, 1.5, 0.0);
-                                camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                                cutscene_state.target_camera_translation = None;
-                                cutscene_state.target_camera_rotation = None;
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "...Are they.. are they trying to do this?".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::Will,
-                                });
-                                bill_animation = Some(game_assets.host_look_right.clone()); 
-                                will_animation = Some(game_assets.host_look_left_talk.clone()); 
-                            },
-                            _ => {
-                                camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                                               game_camera::INGAME_CAMERA_Y, 
-                                                               LEFT_GOAL);
-                                camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                                            game_camera::INGAME_CAMERA_ROTATION_ANGLE);
-                                cutscene_state.current = None;
-                                assets_handler.load(AppState::ResetInGame, &mut game_assets, &game_state);
-                            }
-                        }
+                    if let Some((axis, angle)) = rotation {
+                        cutscene_state.target_camera_rotation = Some(Quat::from_axis_angle(Vec3::from(*axis), *angle));
                     }
-                    _ => {
-                        match cutscene_state.cutscene_index {
-                            0 => {
-                                camera.translation = Vec3::new(19.3, 1.5, 0.0);
-                                camera.rotation = Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247);
-                                cutscene_state.target_camera_translation = None;
-                                cutscene_state.target_camera_rotation = None;
-                                textbox.queued_text = Some(TextBoxText {
-                                    text: "...".to_string(),
-                                    speed: text_speed,
-                                    auto: false,
-                                    speaking: DisplayCharacter::None,
-                                });
-                                bill_animation = Some(game_assets.host_look_right.clone()); 
-                                will_animation = Some(game_assets.host_look_left.clone()); 
-                            },
-                            _ => {
-                                camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                                               game_camera::INGAME_CAMERA_Y, 
-                                                               LEFT_GOAL);
-                                camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                                            game_camera::INGAME_CAMERA_ROTATION_ANGLE);
-                                cutscene_state.current = None;
-                                assets_handler.load(AppState::ResetInGame, &mut game_assets, &game_state);
-                            }
-                        }
+                    cutscene_state.camera_speed = *speed;
+                },
+                CutsceneStep::StopCamera => {
+                    cutscene_state.target_camera_translation = None;
+                    cutscene_state.target_camera_rotation = None;
+                },
+                CutsceneStep::InGameCamera => set_ingame_camera(&mut camera),
+                CutsceneStep::Wait(seconds) => {
+                    if cutscene_state.skipping { continue; }
+
+                    cutscene_state.wait_time = *seconds;
+                    break;
+                },
+                CutsceneStep::StopBgm => audio.stop_bgm(),
+                CutsceneStep::PlayBgm => audio.play_bgm(&game_assets.bgm),
+                CutsceneStep::Load(target) => {
+                    cutscene_state.current = None;
+                    match target {
+                        LoadTarget::ResumeRound => {
+                            // the corn is already out so it isn't spawned again
+                            game_state.corn_spawned = true;
+                            assets_handler.load(AppState::ResetInGame, &mut game_assets, &game_state);
+                        },
+                        LoadTarget::FinishRound => {
+                            finish_round(&rounds, &mut assets_handler, &mut game_assets, &mut game_state);
+                        },
                     }
-                }
+                    break;
+                },
             }
-            _ => ()
         }
     } else {
-        camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                       game_camera::INGAME_CAMERA_Y, 
-                                       LEFT_GOAL);
-        camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                    game_camera::INGAME_CAMERA_ROTATION_ANGLE);
+        set_ingame_camera(&mut camera);
         cutscene_state.current = None;
         assets_handler.load(AppState::ResetInGame, &mut game_assets, &game_state);
     }
@@ -1689,6 +290,14 @@ fn play_cutscene(
     }
 }
 
+fn set_ingame_camera(camera: &mut Transform) {
+    camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
+                                   game_camera::INGAME_CAMERA_Y, 
+                                   LEFT_GOAL);
+    camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
+                                game_camera::INGAME_CAMERA_ROTATION_ANGLE);
+}
+
 fn move_camera(
     mut cutscene_state: ResMut<CutsceneState>,
     mut camera: Query<&mut Transform, With<game_camera::PanOrbitCamera>>,
@@ -1714,6 +323,10 @@ fn setup_cutscene(
     game_assets: Res<GameAssets>,
     mut game_state: ResMut<game_state::GameState>,
     text_scaler: text_size::TextScaler,
+    cutscene_state: Res<CutsceneState>,
+    game_rng: Res<rng::GameRng>,
+    rounds: Res<rounds::Rounds>,
+    localization: Res<Localization>,
 ) {
     commands
         .spawn_bundle(InputManagerBundle {
@@ -1722,6 +335,32 @@ fn setup_cutscene(
         })
         .insert(CleanupMarker);
 
+    // show the seed on the results so a run can be shared and replayed with --seed
+    if cutscene_state.current.as_ref() == Some(&rounds.get(game_state.current_round).outro) {
+        commands
+            .spawn_bundle(TextBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    position: UiRect {
+                        top: Val::Percent(2.0),
+                        right: Val::Percent(2.0),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                text: Text::from_section(
+                    localization.get("results.seed").replace("{seed}", &game_rng.seed().to_string()),
+                    TextStyle {
+                        font: localization.font(&game_assets),
+                        font_size: text_scaler.scale(30.0),
+                        color: Color::WHITE,
+                    }
+                ),
+                ..Default::default()
+            })
+            .insert(CleanupMarker);
+    }
+
     let scale = (text_scaler.window_size.width * 0.1) / ingame::RENDER_TEXTURE_SIZE as f32;
 
     commands
@@ -1764,7 +403,7 @@ fn setup_cutscene(
         });
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct CutsceneEvent {
     pub cutscene: Cutscene
 }
@@ -1774,7 +413,7 @@ fn handle_cutscene_event(
     mut cutscene_state: ResMut<CutsceneState>,
 ) {
     for event in cutscene_event_reader.iter() {
-        cutscene_state.init(event.cutscene);
+        cutscene_state.init(event.cutscene.clone());
     }
 
     if cutscene_state.current.is_some() {
@@ -1821,7 +460,8 @@ pub struct TextBoxText {
     speaking: DisplayCharacter,
 }
 
-enum DisplayCharacter {
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub enum DisplayCharacter {
     Bill, Will, None
 }
 
@@ -1840,6 +480,7 @@ fn display_textbox(
     text_scaler: text_size::TextScaler,
     time: Res<Time>,
     mut audio: GameAudio,
+    localization: Res<Localization>,
 ) {
     textbox.cooldown -= time.delta_seconds();     
     textbox.cooldown = textbox.cooldown.clamp(-3.0, 3.0);
@@ -1875,7 +516,7 @@ fn display_textbox(
                             text: Text::from_section(
                                 text_to_display.trim(),
                                 TextStyle {
-                                    font: game_assets.font.clone(),
+                                    font: localization.font(&game_assets),
                                     font_size,
                                     color: Color::WHITE,
                                 }
@@ -1899,7 +540,7 @@ fn display_textbox(
     textbox.cooldown = current_speed.unwrap_or(textbox.cooldown);
 }
 
-fn handle_input(
+pub fn handle_input(
     mut commands: Commands,
     action_state: Query<&ActionState<MenuAction>>,
     mut textbox: ResMut<TextBox>,
@@ -1918,7 +559,6 @@ fn handle_input(
         if action_state.just_pressed(MenuAction::Select) {
             cutscene_state.input_cooldown = 0.5;
             cutscene_state.waiting_on_input = false;
-            cutscene_state.cutscene_index += 1;
             // clear out existing text
             for children in text_container.iter() {
                 for entity in children.iter() {
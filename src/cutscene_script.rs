@@ -0,0 +1,147 @@
+use crate::{assets::GameAssets, cutscene::{Cutscene, DisplayCharacter}};
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// Every cutscene is a script in assets/data/game.cutscenes.ron, keyed by the name a Cutscene
+// refers to. cutscene::play_cutscene runs the steps in order and stops at each line of dialogue
+// until the player continues.
+
+pub struct CutsceneScriptPlugin;
+impl Plugin for CutsceneScriptPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<CutsceneScripts>()
+            .init_asset_loader::<CutsceneScriptsLoader>();
+    }
+}
+
+pub const DEFAULT_CAMERA_SPEED: f32 = 2.0;
+
+#[derive(Clone, Debug, Deserialize, TypeUuid)]
+#[uuid = "1e8c7f52-93d4-4a0b-b6f1-7c2d5e9a4f36"]
+pub struct CutsceneScripts {
+    pub scripts: HashMap::<String, Vec::<CutsceneStep>>,
+}
+
+impl CutsceneScripts {
+    pub fn get(&self, cutscene: &Cutscene) -> Option<&Vec::<CutsceneStep>> {
+        self.scripts.get(&cutscene.0).or_else(|| {
+            // numbered cutscenes like death_4 fall back to the plain one when there's no script for that number
+            let (name, number) = cutscene.0.rsplit_once('_')?;
+            number.parse::<usize>().ok()?;
+            self.scripts.get(name)
+        })
+    }
+}
+
+// an axis and an angle in radians like Quat::from_axis_angle takes
+pub type AxisAngle = ((f32, f32, f32), f32);
+
+#[derive(Clone, Debug, Deserialize)]
+pub enum CutsceneStep {
+    // shows a line in the textbox, the script waits here until the player continues
+    Say(DisplayCharacter, String),
+    // runs the step paired with the highest score the player has reached
+    ByScore(Vec::<(usize, CutsceneStep)>),
+    Animate {
+        bill: HostAnimation,
+        will: HostAnimation,
+    },
+    // jumps the camera straight there and stops any move in progress
+    CutCamera {
+        translation: (f32, f32, f32),
+        rotation: AxisAngle,
+    },
+    // eases the camera towards a new spot, anything left out keeps heading where it was
+    MoveCamera {
+        #[serde(default)]
+        translation: Option<(f32, f32, f32)>,
+        #[serde(default)]
+        rotation: Option<AxisAngle>,
+        #[serde(default = "default_camera_speed")]
+        speed: f32,
+    },
+    // leaves the camera wherever it got to
+    StopCamera,
+    // puts the camera back where it sits during play
+    InGameCamera,
+    // seconds to hold before the next step without waiting on the player
+    Wait(f32),
+    StopBgm,
+    PlayBgm,
+    // ends the cutscene
+    Load(LoadTarget),
+}
+
+fn default_camera_speed() -> f32 {
+    DEFAULT_CAMERA_SPEED
+}
+
+impl CutsceneStep {
+    // the step to actually run, None if a ByScore has nothing for the score so far
+    pub fn resolve(&self, score: usize) -> Option<&CutsceneStep> {
+        match self {
+            CutsceneStep::ByScore(choices) => {
+                choices.iter()
+                       .filter(|(threshold, _)| *threshold <= score)
+                       .max_by_key(|(threshold, _)| *threshold)
+                       .and_then(|(_, step)| step.resolve(score))
+            },
+            step => Some(step),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub enum HostAnimation {
+    Idle,
+    LookLeft,
+    LookRight,
+    Talk,
+    LookLeftTalk,
+    LookRightTalk,
+}
+
+impl HostAnimation {
+    pub fn clip(&self, game_assets: &GameAssets) -> Handle<AnimationClip> {
+        match self {
+            HostAnimation::Idle => game_assets.host_idle.clone(),
+            HostAnimation::LookLeft => game_assets.host_look_left.clone(),
+            HostAnimation::LookRight => game_assets.host_look_right.clone(),
+            HostAnimation::Talk => game_assets.host_talk.clone(),
+            HostAnimation::LookLeftTalk => game_assets.host_look_left_talk.clone(),
+            HostAnimation::LookRightTalk => game_assets.host_look_right_talk.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub enum LoadTarget {
+    // back into the round on the same field
+    ResumeRound,
+    // on to the next round, or the title screen after the last one
+    FinishRound,
+}
+
+#[derive(Default)]
+pub struct CutsceneScriptsLoader;
+
+impl AssetLoader for CutsceneScriptsLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let scripts: CutsceneScripts = ron::de::from_bytes(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(scripts));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["cutscenes.ron"]
+    }
+}
@@ -89,6 +89,12 @@ impl From<Direction> for Vec3 {
     }
 }
 
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Vec2 {
+        direction.unit_vector
+    }
+}
+
 impl Neg for Direction {
     type Output = Self;
 
@@ -99,3 +105,56 @@ impl Neg for Direction {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_unit(direction: Direction) -> bool {
+        (Vec2::from(direction).length() - 1.0).abs() < 1e-5
+    }
+
+    #[test]
+    fn new_normalizes() {
+        assert_eq!(Direction::new(Vec2::new(3.0, 0.0)), Direction::UP);
+        assert_eq!(Direction::new(Vec2::new(0.0, -0.01)), Direction::LEFT);
+        assert!(is_unit(Direction::new(Vec2::new(3.0, 4.0))));
+        assert!(Vec2::from(Direction::new(Vec2::new(3.0, 4.0))).abs_diff_eq(Vec2::new(0.6, 0.8), 1e-6));
+    }
+
+    #[test]
+    fn new_with_nothing_is_neutral() {
+        assert_eq!(Direction::new(Vec2::ZERO), Direction::NEUTRAL);
+    }
+
+    #[test]
+    fn opposites_cancel_out() {
+        assert_eq!(Direction::UP + Direction::DOWN, Direction::NEUTRAL);
+        assert_eq!(Direction::LEFT + Direction::RIGHT, Direction::NEUTRAL);
+        assert_eq!(Direction::UP - Direction::UP, Direction::NEUTRAL);
+
+        let mut direction = Direction::RIGHT;
+        direction += Direction::LEFT;
+        assert_eq!(direction, Direction::NEUTRAL);
+    }
+
+    #[test]
+    fn diagonals_are_unit_length() {
+        let up_right = Direction::UP + Direction::RIGHT;
+        assert!(is_unit(up_right));
+        assert_eq!(up_right, Direction::new(Vec2::new(1.0, 1.0)));
+
+        let mut down_left = Direction::DOWN;
+        down_left -= Direction::RIGHT;
+        assert!(is_unit(down_left));
+        assert_eq!(down_left, -up_right);
+    }
+
+    #[test]
+    fn converts_onto_the_ground() {
+        assert_eq!(Vec2::from(Direction::UP), Vec2::new(1.0, 0.0));
+        assert_eq!(Vec3::from(Direction::UP), Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(Vec3::from(Direction::LEFT), Vec3::new(0.0, 0.0, -1.0));
+        assert_eq!(Vec3::from(Direction::NEUTRAL), Vec3::ZERO);
+    }
+}
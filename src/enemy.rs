@@ -1,4 +1,4 @@
-use crate::{AppState, game_controller, direction, game_state, collision, assets::GameAssets, component_adder::AnimationLink, ZeroSignum, maze, player, LEFT_GOAL, RIGHT_GOAL, TOP_END, BOTTOM_END, ingame, audio::GameAudio};
+use crate::{AppState, commentary, game_controller, direction, game_state, collision, assets::GameAssets, component_adder::AnimationLink, enemy_archetypes, ZeroSignum, maze, pathing, player, LEFT_GOAL, RIGHT_GOAL, TOP_END, BOTTOM_END, ingame, audio::GameAudio, rng, rounds, timestep::{self, FixedEventAppExt}};
 use bevy::prelude::*;
 use rand::Rng;
 use std::collections::HashMap;
@@ -9,16 +9,21 @@ use bevy::gltf::Gltf;
 pub struct EnemyPlugin;
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(
-            SystemSet::on_update(AppState::InGame)
+        app.add_system_set_to_stage(
+            timestep::FixedUpdateStage,
+            timestep::gameplay()
                 .with_system(handle_spawn_enemies_event)
                 .with_system(scale_lines_of_sight)
                 .with_system(handle_flying_enemies)
-                .with_system(handle_enemy_blade_event)
-                .with_system(move_enemy.after(scale_lines_of_sight)),
+                .with_system(handle_enemy_blade_event.after(release_respawned_runner))
+                .with_system(release_respawned_runner)
+                .with_system(move_enemy.after(scale_lines_of_sight)
+                                       .after(handle_flying_enemies)
+                                       .after(handle_enemy_blade_event)),
         )
-        .add_event::<SpawnEnemiesEvent>()
-        .add_event::<EnemyBladeEvent>();
+        .add_fixed_event::<SpawnEnemiesEvent>()
+        .add_fixed_event::<EnemyBladeEvent>()
+        .add_fixed_event::<ReleaseEnemyEvent>();
     }
 }
 
@@ -27,53 +32,239 @@ pub struct SpawnEnemiesEvent;
 #[derive(Component)]
 pub struct Enemy {
     pub line_of_sight: Entity,
-    pub can_see_player: bool,
+    pub state: EnemyState,
     pub velocity: Vec3,
     pub speed: f32,
-    pub patrol_time: f32,
     pub rotation_speed: f32,
-    pub has_dived: bool,
-    pub is_attached: bool,
-    pub is_launched: bool,
     pub friction: f32,
+    pub vision_length: f32,
+    pub vision_width: f32,
+    pub dive_distance: f32,
+    pub attach_strength: f32,
     pub random: f32,
     pub current_animation: Handle::<AnimationClip>,
     pub landing_target: Vec3,
     pub launch_starting_position: Vec3,
     pub current_flying_time: f32,
+    // where the enemy was spawned, it patrols around here
+    pub post: Vec3,
+    // the runner it's after, or holding on to once it's attached
+    pub target: Option<Entity>,
 }
 
-impl Enemy {
-    pub fn new(line_of_sight: Entity) -> Self {
-        let mut rng = rand::thread_rng();
+// how long enemies look around where they lost the player before going back on patrol
+const SEARCH_TIME: f32 = 6.0;
+// how long enemies stay down after landing from a combine hit
+const STUN_TIME: f32 = 1.5;
+const ATTACH_DISTANCE: f32 = 0.75;
+// enemies that flank go for where the player was heading instead of where they were
+const FLANK_DISTANCE: f32 = 6.0;
+// patrols walk a square around the enemy's post
+const PATROL_RADIUS: f32 = 4.0;
+const PATROL_OFFSETS: [Vec3; 4] = [Vec3::X, Vec3::Z, Vec3::NEG_X, Vec3::NEG_Z];
+const ARRIVAL_DISTANCE: f32 = 1.0;
+const WAYPOINT_REACHED_DISTANCE: f32 = 0.6;
+// paths are only recalculated this often while the combine is clearing corn
+const REPLAN_INTERVAL: f32 = 0.5;
 
+impl Enemy {
+    pub fn new(line_of_sight: Entity, post: Vec3, archetype: &enemy_archetypes::EnemyArchetype, rng: &mut impl Rng) -> Self {
         Enemy {
             line_of_sight,
-            can_see_player: false,
+            state: EnemyState::Patrol { waypoint: 0 },
             velocity: Vec3::default(),
-            speed: 42.0,
+            speed: archetype.speed,
             rotation_speed: 1.0,
-            friction: 0.01,
-            patrol_time: 0.0,
-            has_dived: false,
-            is_attached: false,
+            friction: archetype.friction,
+            vision_length: archetype.vision_length,
+            vision_width: archetype.vision_width,
+            dive_distance: archetype.dive_distance,
+            attach_strength: archetype.attach_strength,
             random: rng.gen_range(0.5..1.0),
             current_animation: Handle::<AnimationClip>::default(),
-            is_launched: false,
             landing_target: Vec3::default(),
             launch_starting_position: Vec3::default(),
             current_flying_time: 0.0,
+            post,
+            target: None,
+        }
+    }
+
+    fn is_flanker(&self) -> bool {
+        self.random > 0.75
+    }
+
+    // where the enemy is walking to through the corn, if anywhere
+    fn destination(&self) -> Option<Vec3> {
+        match self.state {
+            EnemyState::Patrol { waypoint } => Some(self.post + PATROL_OFFSETS[waypoint % PATROL_OFFSETS.len()] * PATROL_RADIUS),
+            EnemyState::Search { last_known, heading, .. } => {
+                if self.is_flanker() {
+                    Some(last_known + heading * FLANK_DISTANCE)
+                } else {
+                    Some(last_known)
+                }
+            },
+            _ => None,
         }
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EnemyState {
+    // walking between the corners of a square around its post
+    Patrol { waypoint: usize },
+    // running straight at the player while they're in sight
+    Chase,
+    // sliding at the player, attaches if it gets close enough
+    Dive,
+    // hanging on to the player and slowing them down
+    Attached,
+    // knocked into the air by the combine
+    Launched,
+    // just landed and getting back up
+    Stunned { time_left: f32 },
+    // lost sight of the player and heading for where they were last seen
+    Search { last_known: Vec3, heading: Vec3, time_left: f32 },
+}
+
+// Everything an enemy notices in a step, transition only looks at this and the current state
+#[derive(Default)]
+pub struct EnemySenses {
+    pub can_see_player: bool,
+    pub player_position: Vec3,
+    pub player_velocity: Vec3,
+    pub in_dive_range: bool,
+    pub in_attach_range: bool,
+    pub is_moving: bool,
+    // reached its patrol waypoint or search spot, or it can't get any closer
+    pub at_destination: bool,
+    pub hit_by_blade: bool,
+    pub landed: bool,
+    pub delta: f32,
+}
+
+pub fn transition(state: EnemyState, senses: &EnemySenses) -> EnemyState {
+    if senses.hit_by_blade && state != EnemyState::Launched {
+        return EnemyState::Launched;
+    }
+
+    let lost_player = EnemyState::Search {
+        last_known: senses.player_position,
+        heading: senses.player_velocity.normalize_or_zero(),
+        time_left: SEARCH_TIME,
+    };
+
+    match state {
+        EnemyState::Patrol { waypoint } => {
+            if senses.can_see_player {
+                EnemyState::Chase
+            } else if senses.at_destination {
+                EnemyState::Patrol { waypoint: (waypoint + 1) % PATROL_OFFSETS.len() }
+            } else {
+                state
+            }
+        },
+        EnemyState::Chase => {
+            if !senses.can_see_player {
+                lost_player
+            } else if senses.in_dive_range {
+                EnemyState::Dive
+            } else {
+                state
+            }
+        },
+        EnemyState::Dive => {
+            if senses.in_attach_range {
+                EnemyState::Attached
+            } else if senses.is_moving {
+                state
+            } else if senses.can_see_player {
+                EnemyState::Chase
+            } else {
+                lost_player
+            }
+        },
+        // only the combine knocks them off
+        EnemyState::Attached => state,
+        EnemyState::Launched => {
+            if senses.landed {
+                EnemyState::Stunned { time_left: STUN_TIME }
+            } else {
+                state
+            }
+        },
+        EnemyState::Stunned { time_left } => {
+            if time_left <= senses.delta {
+                EnemyState::Patrol { waypoint: 0 }
+            } else {
+                EnemyState::Stunned { time_left: time_left - senses.delta }
+            }
+        },
+        EnemyState::Search { last_known, heading, time_left } => {
+            if senses.can_see_player {
+                EnemyState::Chase
+            } else if time_left <= senses.delta {
+                EnemyState::Patrol { waypoint: 0 }
+            } else {
+                EnemyState::Search { last_known, heading, time_left: time_left - senses.delta }
+            }
+        },
+    }
+}
+
+// The route an enemy follows when it can't see where it's going
+#[derive(Component, Default)]
+pub struct EnemyPath {
+    waypoints: Vec::<Vec3>,
+    goal: Option<pathing::Cell>,
+    version: Option<u64>,
+    replan_cooldown: f32,
+}
+
+impl EnemyPath {
+    fn clear(&mut self) {
+        self.waypoints.clear();
+        self.goal = None;
+    }
+
+    // the next point to head for, planning a new path first if the goal moved to another cell
+    // or the corn changed since the last one
+    fn next_waypoint(&mut self, path_grid: &pathing::PathGrid, from: Vec3, goal: Vec3, delta: f32) -> Option<Vec3> {
+        self.replan_cooldown -= delta;
+
+        let goal_cell = path_grid.cell(goal);
+        let is_stale = self.waypoints.is_empty() || self.version != path_grid.version();
+        if self.goal != goal_cell || (is_stale && self.replan_cooldown <= 0.0) {
+            self.waypoints = path_grid.find_path(from, goal).unwrap_or_default();
+            self.goal = goal_cell;
+            self.version = path_grid.version();
+            self.replan_cooldown = REPLAN_INTERVAL;
+        }
+
+        while self.waypoints.first().map_or(false, |w| w.distance(from) < WAYPOINT_REACHED_DISTANCE) {
+            self.waypoints.remove(0);
+        }
+
+        self.waypoints.first().copied()
+    }
+}
+
 #[derive(Component)]
-pub struct EnemyLineOfSight;
+pub struct EnemyLineOfSight {
+    // the closest runner in sight
+    pub seen_player: Option<Entity>,
+}
 
 pub struct EnemyBladeEvent {
     pub entity: Entity
 }
 
+// thrown off the same way as a blade hit but nothing hit it, so nobody comments on it
+pub struct ReleaseEnemyEvent {
+    pub entity: Entity
+}
+
 fn handle_spawn_enemies_event( 
     mut commands: Commands,
     mut spawn_enemies_event_reader: EventReader<SpawnEnemiesEvent>,
@@ -83,63 +274,75 @@ fn handle_spawn_enemies_event(
     collidables: collision::Collidables,
     assets_gltf: Res<Assets<Gltf>>,
     game_state: Res<game_state::GameState>,
+    archetype_assets: Res<Assets<enemy_archetypes::EnemyArchetypes>>,
+    mut game_rng: ResMut<rng::GameRng>,
+    rounds: Res<rounds::Rounds>,
 ) {
     for event in spawn_enemies_event_reader.iter() {
-        let enemy_count = match game_state.current_round {
-            1 => 5,
-            2 => 6,
-            _ => 3
-        };
+        let default_archetypes = enemy_archetypes::EnemyArchetypes::default();
+        let archetypes = archetype_assets.get(&game_assets.enemy_archetypes)
+                                         .unwrap_or(&default_archetypes);
 
-        if let Some(gltf) = assets_gltf.get(&game_assets.enemy.clone()) {
-            for _ in 0..enemy_count {
-                let mut target = None;
-                let mut rng = rand::thread_rng();
-                let z_buffer = ((RIGHT_GOAL - LEFT_GOAL).abs() * 0.25);
-                let x_buffer = ((TOP_END - BOTTOM_END).abs() * 0.02);
-                let min_z = LEFT_GOAL + z_buffer;
-                let max_z = RIGHT_GOAL - z_buffer;
-                let min_x = BOTTOM_END + x_buffer;
-                let max_x = TOP_END - x_buffer;
-                while target.is_none() {
-                    let potential_position = Vec3::new(rng.gen_range(min_x..max_x), 
-                                                       0.0, 
-                                                       rng.gen_range(min_z..max_z));
-                    if !collidables.is_in_collidable(&potential_position) {
-                        target = Some(potential_position);
-                    }
+        for archetype in archetypes.mix(&rounds.get(game_state.current_round).enemies) {
+            let mut target = None;
+            let rng = game_rng.stream(rng::RngStream::Enemy);
+            let z_buffer = ((RIGHT_GOAL - LEFT_GOAL).abs() * 0.25);
+            let x_buffer = ((TOP_END - BOTTOM_END).abs() * 0.02);
+            let min_z = LEFT_GOAL + z_buffer;
+            let max_z = RIGHT_GOAL - z_buffer;
+            let min_x = BOTTOM_END + x_buffer;
+            let max_x = TOP_END - x_buffer;
+            while target.is_none() {
+                let potential_position = Vec3::new(rng.gen_range(min_x..max_x), 
+                                                   0.0, 
+                                                   rng.gen_range(min_z..max_z));
+                if !collidables.is_in_collidable(&potential_position) {
+                    target = Some(potential_position);
                 }
+            }
+
+            let target = target.expect("uhh this was populated a second ago");
 
-                let target = target.expect("uhh this was populated a second ago");
-
-                let line_of_sight_id = commands
-                    .spawn_bundle(PbrBundle {
-                        mesh: meshes.add(Mesh::from(shape::Box::default())),
-                        material: materials.add(StandardMaterial {
-                            unlit: true,
-                            base_color: Color::rgba(1.0, 0.0, 0.0, 0.6),
-                            alpha_mode: AlphaMode::Blend,
-                            ..Default::default()
-                        }),
-                        visibility: Visibility {
-                            is_visible: false
-                        },
-                        transform: Transform::from_scale(Vec3::ZERO),
+            let line_of_sight_id = commands
+                .spawn_bundle(PbrBundle {
+                    mesh: meshes.add(Mesh::from(shape::Box::default())),
+                    material: materials.add(StandardMaterial {
+                        unlit: true,
+                        base_color: Color::rgba(1.0, 0.0, 0.0, 0.6),
+                        alpha_mode: AlphaMode::Blend,
                         ..Default::default()
-                    })
-                    .insert(EnemyLineOfSight { })
-                    .insert(ingame::CleanupMarker)
-                    .id();
-                commands.spawn_bundle(SceneBundle {
-                            scene: gltf.scenes[0].clone(),
-                            transform: Transform::from_xyz(target.x, 0.0, target.z),
-                            ..default()
-                        })
-                        .insert(Enemy::new(line_of_sight_id))
-                        .insert(AnimationLink {
-                            entity: None
-                        })
-                        .insert(ingame::CleanupMarker);
+                    }),
+                    visibility: Visibility {
+                        is_visible: false
+                    },
+                    transform: Transform::from_scale(Vec3::ZERO),
+                    ..Default::default()
+                })
+                .insert(EnemyLineOfSight { seen_player: None })
+                // same bounds as shape::Box::default(), the renderer won't compute one when headless
+                .insert(Aabb::from_min_max(Vec3::splat(-0.5), Vec3::splat(0.5)))
+                .insert(ingame::CleanupMarker)
+                .id();
+            let mut enemy = commands.spawn_bundle(SpatialBundle::from_transform(
+                                        Transform::from_xyz(target.x, 0.0, target.z)
+                                    ));
+            enemy.insert(Enemy::new(line_of_sight_id, target, archetype, game_rng.stream(rng::RngStream::Enemy)))
+                 .insert(EnemyPath::default())
+                 .insert(timestep::Interpolated::default())
+                 .insert(AnimationLink {
+                     entity: None
+                 })
+                 .insert(ingame::CleanupMarker);
+
+            let gltf = archetype.scene
+                                .as_ref()
+                                .and_then(|scene| assets_gltf.get(scene))
+                                .or_else(|| assets_gltf.get(&game_assets.enemy.clone()));
+            if let Some(gltf) = gltf {
+                enemy.insert(gltf.scenes[0].clone());
+                if archetype.tint != (1.0, 1.0, 1.0) {
+                    enemy.insert(enemy_archetypes::EnemyTint(archetype.tint));
+                }
             }
         }
     }
@@ -147,13 +350,13 @@ fn handle_spawn_enemies_event(
 
 pub fn handle_flying_enemies(
     mut enemies: Query<(&mut Enemy, &mut Transform)>,
-    time: Res<Time>,
+    time: Res<timestep::FixedTime>,
 ) {
     let flight_time = 2.0;
     let flight_height = 20.0;
 
     for (mut enemy, mut transform) in &mut enemies {
-        if enemy.is_launched {
+        if enemy.state == EnemyState::Launched {
             let (target_with_height, start_with_height) 
                 = if enemy.current_flying_time / flight_time <= 0.5 {
                      (Vec3::new(enemy.landing_target.x, flight_height, enemy.landing_target.z),
@@ -171,7 +374,7 @@ pub fn handle_flying_enemies(
 
             if enemy.current_flying_time >= flight_time {
                 enemy.current_flying_time = 0.0;
-                enemy.is_launched = false;
+                enemy.state = transition(enemy.state, &EnemySenses { landed: true, ..default() });
                 transform.translation.y = 0.0;
                 transform.rotation = Quat::IDENTITY;
             }
@@ -181,13 +384,17 @@ pub fn handle_flying_enemies(
 
 pub fn handle_enemy_blade_event(
     mut enemy_blade_event_reader: EventReader<EnemyBladeEvent>,
+    mut release_enemy_event_reader: EventReader<ReleaseEnemyEvent>,
     mut enemies: Query<(&mut Enemy, &Transform, &AnimationLink)>,
     mut animations: Query<&mut AnimationPlayer>,
     game_assets: ResMut<GameAssets>,
     collidables: collision::Collidables,
+    mut players: Query<&mut player::Player>,
+    mut game_rng: ResMut<rng::GameRng>,
 ) {
-    for event in enemy_blade_event_reader.iter() {
-        if let Ok((mut enemy, transform, animation_link)) = enemies.get_mut(event.entity) {
+    let released = release_enemy_event_reader.iter().map(|event| event.entity);
+    for entity in enemy_blade_event_reader.iter().map(|event| event.entity).chain(released) {
+        if let Ok((mut enemy, transform, animation_link)) = enemies.get_mut(entity) {
             if let Some(animation_entity) = animation_link.entity {
                 let mut animation = animations.get_mut(animation_entity).unwrap();
                 animation.play(game_assets.person_dive.clone_weak());
@@ -196,7 +403,7 @@ pub fn handle_enemy_blade_event(
             }
 
             let mut target = None;
-            let mut rng = rand::thread_rng();
+            let rng = game_rng.stream(rng::RngStream::Enemy);
             let z_buffer = ((RIGHT_GOAL - LEFT_GOAL).abs() * 0.25);
             let x_buffer = ((TOP_END - BOTTOM_END).abs() * 0.02);
             let min_z = LEFT_GOAL + z_buffer;
@@ -212,49 +419,53 @@ pub fn handle_enemy_blade_event(
                 }
             }
 
+            if enemy.state == EnemyState::Attached {
+                // knocked off the player
+                if let Some(mut player) = enemy.target.and_then(|target| players.get_mut(target).ok()) {
+                    player.attached_enemies = player.attached_enemies.saturating_sub(1);
+                    player.attached_strength = (player.attached_strength - enemy.attach_strength).max(0.0);
+                }
+            }
+
             enemy.landing_target = target.unwrap();
             enemy.launch_starting_position = transform.translation;
             enemy.current_flying_time = 0.0;
-            enemy.is_launched = true;
+            enemy.state = transition(enemy.state, &EnemySenses { hit_by_blade: true, ..default() });
         }
     }
 }
 
 fn scale_lines_of_sight(
-    mut enemies: Query<(&mut Enemy, &Transform), Without<EnemyLineOfSight>>,
-    mut lines_of_sight: Query<(&mut Transform, &Aabb, &GlobalTransform), With<EnemyLineOfSight>>,
+    enemies: Query<(&Enemy, &Transform), Without<EnemyLineOfSight>>,
+    mut lines_of_sight: Query<(&mut EnemyLineOfSight, &mut Transform, &Aabb, &GlobalTransform)>,
     corns: Query<(&maze::CornStalk, &Transform), Without<EnemyLineOfSight>>,
-    player: Query<&Transform, (Without<EnemyLineOfSight>, With<player::Player>)>,
+    players: Query<(Entity, &Transform), (Without<EnemyLineOfSight>, With<player::Player>)>,
 ) {
     let unharvested_corn = corns.iter()
                                 .filter(|(c, _)| !c.is_harvested)
                                 .collect::<Vec::<_>>();
-    let LOS_LENGTH = 15.0;
-    for (mut enemy, enemy_transform) in &mut enemies {
-        if let Ok((mut line_of_sight, los_aabb, los_global_transform)) = lines_of_sight.get_mut(enemy.line_of_sight) {
+    for (enemy, enemy_transform) in &enemies {
+        if let Ok((mut sight, mut line_of_sight, los_aabb, los_global_transform)) = lines_of_sight.get_mut(enemy.line_of_sight) {
             let los_global_matrix = los_global_transform.compute_matrix();
             let los_inverse_transform_matrix = los_global_matrix.inverse();
             let los_min: Vec3 = los_aabb.min().into();
             let los_max: Vec3 = los_aabb.max().into();
 
             let direction = enemy_transform.right().normalize();
-            let end_of_sight = enemy_transform.translation + (LOS_LENGTH * direction);
-
-            // check for player first
-            let distance_to_player = {
-                let player = player.single();
-                let player_translation = player.translation;
-                let player_inverse = los_inverse_transform_matrix.transform_point3(player_translation);
-                let player_in_hitbox = player_inverse.x > los_min.x
-                                    && player_inverse.x < los_max.x
-                                    && player_inverse.z > los_min.z
-                                    && player_inverse.z < los_max.z;
-                if player_in_hitbox {
-                    Some(player.translation.distance(enemy_transform.translation))
-                } else {
-                    None
-                }
-            };
+            let end_of_sight = enemy_transform.translation + (enemy.vision_length * direction);
+
+            // check for players first, the closest one in the box is the one that counts
+            let closest_player = 
+                players.iter()
+                       .filter(|(_, player)| {
+                           let player_inverse = los_inverse_transform_matrix.transform_point3(player.translation);
+                           player_inverse.x > los_min.x
+                        && player_inverse.x < los_max.x
+                        && player_inverse.z > los_min.z
+                        && player_inverse.z < los_max.z
+                       })
+                       .map(|(entity, player)| (entity, player.translation.distance(enemy_transform.translation)))
+                       .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
 
             let mut corn_in_front_of_enemy = 
                 unharvested_corn.iter()
@@ -283,14 +494,14 @@ fn scale_lines_of_sight(
             let middle = enemy_transform.translation.lerp(end_of_sight, 0.5);
             let line_of_sight_length = enemy_transform.translation.distance(end_of_sight);
 
-            enemy.can_see_player = false;
-            if let Some(distance_to_player) = distance_to_player {
+            sight.seen_player = None;
+            if let Some((player_entity, distance_to_player)) = closest_player {
                 if line_of_sight_length > distance_to_player {
-                    enemy.can_see_player = true;
+                    sight.seen_player = Some(player_entity);
                 }
             } 
 
-            line_of_sight.scale = Vec3::new(line_of_sight_length / 2.0, 1.00, 1.00);
+            line_of_sight.scale = Vec3::new(line_of_sight_length / 2.0, 1.00, enemy.vision_width);
             line_of_sight.translation = Vec3::new(middle.x, 1.0, middle.z);
 
             // Rotate the direction indicator
@@ -305,48 +516,74 @@ fn scale_lines_of_sight(
     }
 }
 
+
 fn move_enemy(
-    mut enemies: Query<(&mut Enemy, &mut Transform, &AnimationLink)>,
+    mut enemies: Query<(&mut Enemy, &mut EnemyPath, &mut Transform, &AnimationLink)>,
+    lines_of_sight: Query<&EnemyLineOfSight>,
     mut animations: Query<&mut AnimationPlayer>,
-    player: Query<&Transform, (With<player::Player>, Without<Enemy>)>,
+    mut players: Query<(Entity, &Transform, &mut player::Player), Without<Enemy>>,
     collidables: collision::Collidables,
-    mut game_state: ResMut<game_state::GameState>, 
-    time: Res<Time>,
+    path_grid: Res<pathing::PathGrid>,
+    time: Res<timestep::FixedTime>,
     game_assets: ResMut<GameAssets>,
     mut audio: GameAudio,
+    mut commentary_event_writer: EventWriter<commentary::CommentaryEvent>,
 ) {
-    for (mut enemy, mut enemy_transform, animation_link) in &mut enemies {
-        if enemy.is_launched { continue; }
-
-        let speed: f32 = enemy.speed;
-        let rotation_speed: f32 = enemy.rotation_speed;
-        let friction: f32 = enemy.friction + if enemy.has_dived { 0.1 } else { 0.0 };
-
-        enemy.velocity *= friction.powf(time.delta_seconds());
-
-        let player = player.single();
-        if enemy.has_dived && player.translation.distance(enemy_transform.translation) < 0.75 {
-            enemy.is_attached = true;
-            audio.play_sfx(&game_assets.attach);
-            enemy.has_dived = false;
-            game_state.attached_enemies += 1;
-        }
-
-        if enemy.is_attached {
-            enemy_transform.translation = player.translation;
-            enemy_transform.rotation = enemy_transform.rotation.lerp(player.rotation, 1.25 * enemy.random);
+    for (mut enemy, mut path, mut enemy_transform, animation_link) in &mut enemies {
+        if enemy.state == EnemyState::Launched {
+            path.clear();
             continue;
         }
 
-        if enemy.can_see_player && !enemy.has_dived {
-            let direction = player.translation - enemy_transform.translation;
-            let acceleration = Vec3::from(direction);
+        let speed: f32 = enemy.speed;
+        let seen_player = lines_of_sight.get(enemy.line_of_sight)
+                                        .ok()
+                                        .and_then(|sight| sight.seen_player);
+        let can_see_player = seen_player.is_some();
+
+        // a dive or a hold stays on who it started on, otherwise it's whoever's in sight, then
+        // whoever it was already after, then whoever's closest
+        let committed = matches!(enemy.state, EnemyState::Dive | EnemyState::Attached);
+        let target = (if committed { enemy.target } else { seen_player.or(enemy.target) })
+                         .filter(|target| players.contains(*target))
+                         .or_else(|| players.iter()
+                                            .map(|(entity, transform, _)| (entity, transform.translation.distance(enemy_transform.translation)))
+                                            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                                            .map(|(entity, _)| entity));
+        enemy.target = target;
+        let (player, player_velocity) = match target.and_then(|target| players.get(target).ok()) {
+            Some((_, transform, player_state)) => (*transform, player_state.velocity),
+            None => continue,
+        };
 
-            enemy.velocity += (acceleration.zero_signum() * speed) * time.delta_seconds();
-            enemy.velocity = enemy.velocity.clamp_length_max(speed);
+        // where to walk through the corn when the player isn't in sight
+        let destination = enemy.destination();
+        let heading_to = destination.and_then(|destination| {
+            if destination.distance(enemy_transform.translation) > ARRIVAL_DISTANCE {
+                path.next_waypoint(&path_grid, enemy_transform.translation, destination, time.delta_seconds())
+            } else {
+                None
+            }
+        });
+        if destination.is_none() {
+            path.clear();
+        }
 
-            if player.translation.distance(enemy_transform.translation) < 3.0 {
-                enemy.has_dived = true;
+        let previous_state = enemy.state;
+        enemy.state = transition(previous_state, &EnemySenses {
+            can_see_player,
+            player_position: player.translation,
+            player_velocity,
+            in_dive_range: player.translation.distance(enemy_transform.translation) < enemy.dive_distance,
+            in_attach_range: player.translation.distance(enemy_transform.translation) < ATTACH_DISTANCE,
+            is_moving: enemy.velocity.length() >= 0.001,
+            at_destination: destination.is_some() && heading_to.is_none(),
+            delta: time.delta_seconds(),
+            ..default()
+        });
+
+        match (previous_state, enemy.state) {
+            (EnemyState::Chase, EnemyState::Dive) => {
                 audio.play_sfx(&game_assets.dive);
                 enemy.velocity = (player.translation - enemy_transform.translation).normalize() * 0.5 * speed;
                 if let Some(animation_entity) = animation_link.entity {
@@ -355,7 +592,43 @@ fn move_enemy(
                     enemy.current_animation = game_assets.person_dive.clone_weak();
                     animation.set_speed(enemy.velocity.length() / 6.0);
                 }
-            }
+            },
+            (EnemyState::Dive, EnemyState::Attached) => {
+                audio.play_sfx(&game_assets.attach);
+                if let Some(mut player_state) = target.and_then(|target| players.get_mut(target).ok()).map(|(_, _, player_state)| player_state) {
+                    player_state.attached_enemies += 1;
+                    player_state.attached_strength += enemy.attach_strength;
+                }
+                commentary_event_writer.send(commentary::CommentaryEvent(commentary::Moment::Attached));
+            },
+            _ => ()
+        }
+
+        let friction: f32 = enemy.friction + if enemy.state == EnemyState::Dive { 0.1 } else { 0.0 };
+        enemy.velocity *= friction.powf(time.delta_seconds());
+
+        match enemy.state {
+            EnemyState::Attached => {
+                enemy_transform.translation = player.translation;
+                enemy_transform.rotation = enemy_transform.rotation.lerp(player.rotation, 1.25 * enemy.random);
+                continue;
+            },
+            EnemyState::Chase => {
+                path.clear();
+                let acceleration = player.translation - enemy_transform.translation;
+                enemy.velocity += (acceleration.zero_signum() * speed) * time.delta_seconds();
+                enemy.velocity = enemy.velocity.clamp_length_max(speed);
+            },
+            EnemyState::Patrol { .. } | EnemyState::Search { .. } => {
+                // patrols are walked, searches are run
+                let speed = if let EnemyState::Patrol { .. } = enemy.state { speed * 0.5 } else { speed };
+                if let Some(waypoint) = heading_to {
+                    let acceleration = waypoint - enemy_transform.translation;
+                    enemy.velocity += (acceleration.zero_signum() * speed) * time.delta_seconds();
+                    enemy.velocity = enemy.velocity.clamp_length_max(speed);
+                }
+            },
+            _ => ()
         }
 
         let mut new_translation = enemy_transform.translation + (enemy.velocity * time.delta_seconds());
@@ -363,51 +636,25 @@ fn move_enemy(
             &enemy_transform.translation,
             &mut new_translation,
             &mut enemy.velocity,
-            &time
         );
 
         enemy_transform.translation = new_translation;
 
-        if enemy.has_dived && enemy.velocity.length() >= 0.001 {
-            continue;
-        } else {
-            enemy.has_dived = false;
-        }
+        if enemy.state == EnemyState::Dive { continue; }
 
-        if enemy.can_see_player {
-            let angle = (-(player.translation.z - enemy_transform.translation.z))
-                .atan2(player.translation.x - enemy_transform.translation.x);
-            let rotation = Quat::from_axis_angle(Vec3::Y, angle);
-
-            if !rotation.is_nan() {
-                enemy_transform.rotation = rotation;
-            }
-        } else {
-//          enemy.patrol_time -= time.delta_seconds();
-
-//          if enemy.patrol_time <= 0.0 {
-//              if enemy_transform.rotation == Quat::from_axis_angle(Vec3::Y, TAU * 0.0) {
-//                  enemy_transform.rotation = Quat::from_axis_angle(Vec3::Y, TAU * 0.25);
-//                  enemy_transform.translation.y += 0.01;
-//              } else if enemy_transform.rotation == Quat::from_axis_angle(Vec3::Y, TAU * 0.25) {
-//                  enemy_transform.rotation = Quat::from_axis_angle(Vec3::Y, TAU * 0.50);
-//                  enemy_transform.translation.x += 0.01;
-//              } else if enemy_transform.rotation == Quat::from_axis_angle(Vec3::Y, TAU * 0.50){
-//                  enemy_transform.rotation = Quat::from_axis_angle(Vec3::Y, TAU * 0.75);
-//                  enemy_transform.translation.y -= 0.01;
-//              } else {
-//                  enemy_transform.rotation = Quat::from_axis_angle(Vec3::Y, TAU * 0.0);
-//                  enemy_transform.translation.x -= 0.01;
-//              }
-
-//              enemy.patrol_time = 3.0 + enemy.random;
-//          }
-//          enemy.patrol_time = enemy.patrol_time.clamp(0.0, 10.0);
-            enemy_transform.rotate_y(time.delta_seconds() * (1.0 + enemy.random));
+        match enemy.state {
+            EnemyState::Chase => face(&mut enemy_transform, player.translation),
+            // look where it's going so it can spot the player again
+            EnemyState::Patrol { .. } | EnemyState::Search { .. } => {
+                if let Some(waypoint) = heading_to {
+                    face(&mut enemy_transform, waypoint);
+                } else {
+                    enemy_transform.rotate_y(time.delta_seconds() * (1.0 + enemy.random));
+                }
+            },
+            _ => ()
         }
 
-        if enemy.has_dived { continue; };
-
         if enemy.velocity.length() > 1.0 {
             if let Some(animation_entity) = animation_link.entity {
                 let mut animation = animations.get_mut(animation_entity).unwrap();
@@ -431,3 +678,106 @@ fn move_enemy(
         }
     }
 }
+
+// whatever was holding on to a runner who just came back in versus gets thrown off
+fn release_respawned_runner(
+    mut respawn_event_reader: EventReader<player::RunnerRespawnEvent>,
+    enemies: Query<(Entity, &Enemy)>,
+    mut release_enemy_event_writer: EventWriter<ReleaseEnemyEvent>,
+) {
+    for event in respawn_event_reader.iter() {
+        for (entity, enemy) in &enemies {
+            if enemy.state == EnemyState::Attached && enemy.target == Some(event.entity) {
+                release_enemy_event_writer.send(ReleaseEnemyEvent { entity });
+            }
+        }
+    }
+}
+
+fn face(transform: &mut Transform, target: Vec3) {
+    let angle = (-(target.z - transform.translation.z))
+        .atan2(target.x - transform.translation.x);
+    let rotation = Quat::from_axis_angle(Vec3::Y, angle);
+
+    if !rotation.is_nan() {
+        transform.rotation = rotation;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search(time_left: f32) -> EnemyState {
+        EnemyState::Search { last_known: Vec3::new(1.0, 0.0, 2.0), heading: Vec3::X, time_left }
+    }
+
+    fn seen() -> EnemySenses {
+        EnemySenses { can_see_player: true, ..default() }
+    }
+
+    #[test]
+    fn transitions() {
+        let table = [
+            ("patrol spots the player", EnemyState::Patrol { waypoint: 0 }, seen(), EnemyState::Chase),
+            ("patrol keeps walking", EnemyState::Patrol { waypoint: 1 }, EnemySenses::default(), EnemyState::Patrol { waypoint: 1 }),
+            ("patrol reaches a corner", EnemyState::Patrol { waypoint: 1 },
+             EnemySenses { at_destination: true, ..default() }, EnemyState::Patrol { waypoint: 2 }),
+            ("patrol goes back round", EnemyState::Patrol { waypoint: 3 },
+             EnemySenses { at_destination: true, ..default() }, EnemyState::Patrol { waypoint: 0 }),
+            ("chase keeps going", EnemyState::Chase, seen(), EnemyState::Chase),
+            ("chase gets close enough to dive", EnemyState::Chase,
+             EnemySenses { can_see_player: true, in_dive_range: true, ..default() }, EnemyState::Dive),
+            ("dive reaches the player", EnemyState::Dive,
+             EnemySenses { in_attach_range: true, is_moving: true, ..default() }, EnemyState::Attached),
+            ("dive still sliding", EnemyState::Dive, EnemySenses { is_moving: true, ..default() }, EnemyState::Dive),
+            ("dive missed but can still see them", EnemyState::Dive, seen(), EnemyState::Chase),
+            ("attached holds on", EnemyState::Attached, seen(), EnemyState::Attached),
+            ("patrol hit by the blade", EnemyState::Patrol { waypoint: 2 },
+             EnemySenses { hit_by_blade: true, ..default() }, EnemyState::Launched),
+            ("chase hit by the blade", EnemyState::Chase,
+             EnemySenses { hit_by_blade: true, can_see_player: true, ..default() }, EnemyState::Launched),
+            ("dive hit by the blade", EnemyState::Dive,
+             EnemySenses { hit_by_blade: true, in_attach_range: true, ..default() }, EnemyState::Launched),
+            ("attached hit by the blade", EnemyState::Attached,
+             EnemySenses { hit_by_blade: true, ..default() }, EnemyState::Launched),
+            ("stunned hit by the blade", EnemyState::Stunned { time_left: 1.0 },
+             EnemySenses { hit_by_blade: true, ..default() }, EnemyState::Launched),
+            ("search hit by the blade", search(1.0),
+             EnemySenses { hit_by_blade: true, ..default() }, EnemyState::Launched),
+            ("launched again stays in the air", EnemyState::Launched,
+             EnemySenses { hit_by_blade: true, ..default() }, EnemyState::Launched),
+            ("launched still flying", EnemyState::Launched, EnemySenses::default(), EnemyState::Launched),
+            ("launched lands", EnemyState::Launched,
+             EnemySenses { landed: true, ..default() }, EnemyState::Stunned { time_left: STUN_TIME }),
+            ("stunned counts down", EnemyState::Stunned { time_left: 1.0 },
+             EnemySenses { delta: 0.5, ..default() }, EnemyState::Stunned { time_left: 0.5 }),
+            ("stunned gets up", EnemyState::Stunned { time_left: 0.5 },
+             EnemySenses { delta: 0.5, ..default() }, EnemyState::Patrol { waypoint: 0 }),
+            ("search spots the player", search(1.0), seen(), EnemyState::Chase),
+            ("search counts down", search(1.0), EnemySenses { delta: 0.5, ..default() }, search(0.5)),
+            ("search gives up", search(0.5), EnemySenses { delta: 0.5, ..default() }, EnemyState::Patrol { waypoint: 0 }),
+        ];
+
+        for (name, state, senses, expected) in table.iter() {
+            assert_eq!(transition(*state, senses), *expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn losing_the_player_searches_where_they_were_heading() {
+        let senses = EnemySenses {
+            player_position: Vec3::new(3.0, 0.0, 4.0),
+            player_velocity: Vec3::new(0.0, 0.0, 10.0),
+            ..default()
+        };
+        let expected = EnemyState::Search {
+            last_known: Vec3::new(3.0, 0.0, 4.0),
+            heading: Vec3::Z,
+            time_left: SEARCH_TIME,
+        };
+
+        assert_eq!(transition(EnemyState::Chase, &senses), expected);
+        assert_eq!(transition(EnemyState::Dive, &senses), expected);
+    }
+}
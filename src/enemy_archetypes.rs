@@ -0,0 +1,166 @@
+use crate::AppState;
+use bevy::asset::{AssetLoader, AssetPath, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::gltf::Gltf;
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use serde::Deserialize;
+use std::path::PathBuf;
+
+// Enemy stats come from assets/data/enemies.archetypes.ron, which ones show up each round is
+// up to the round definitions. If the file isn't loaded (headless runs) the built in default is
+// a single archetype with the stats every enemy used to have.
+
+pub struct EnemyArchetypesPlugin;
+impl Plugin for EnemyArchetypesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<EnemyArchetypes>()
+            .init_asset_loader::<EnemyArchetypesLoader>()
+            .add_system_set(
+                SystemSet::on_update(AppState::InGame)
+                    .with_system(tint_enemy_models)
+            );
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, TypeUuid)]
+#[uuid = "5d6f3c0e-8a4b-4b8e-9a56-4f0e0c7d2b11"]
+pub struct EnemyArchetypes {
+    pub archetypes: Vec::<EnemyArchetype>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EnemyArchetype {
+    pub name: String,
+    pub speed: f32,
+    pub friction: f32,
+    pub vision_length: f32,
+    pub vision_width: f32,
+    // how close the player has to be before it dives
+    pub dive_distance: f32,
+    // how much it slows the player down once attached, the player is tackled at 3.0
+    pub attach_strength: f32,
+    // glb to use instead of models/enemy.glb
+    #[serde(default)]
+    pub model: Option<String>,
+    // multiplied into the model's colors
+    #[serde(default = "no_tint")]
+    pub tint: (f32, f32, f32),
+    #[serde(skip)]
+    pub scene: Option<Handle<Gltf>>,
+}
+
+fn no_tint() -> (f32, f32, f32) {
+    (1.0, 1.0, 1.0)
+}
+
+impl Default for EnemyArchetype {
+    fn default() -> Self {
+        EnemyArchetype {
+            name: "enemy".to_string(),
+            speed: 42.0,
+            friction: 0.01,
+            vision_length: 15.0,
+            vision_width: 1.0,
+            dive_distance: 3.0,
+            attach_strength: 1.0,
+            model: None,
+            tint: no_tint(),
+            scene: None,
+        }
+    }
+}
+
+impl Default for EnemyArchetypes {
+    fn default() -> Self {
+        EnemyArchetypes {
+            archetypes: vec![EnemyArchetype::default()],
+        }
+    }
+}
+
+impl EnemyArchetypes {
+    pub fn get(&self, name: &str) -> Option<&EnemyArchetype> {
+        self.archetypes.iter().find(|archetype| archetype.name == name)
+    }
+
+    // every enemy to spawn for a list of archetype names and counts, in the order they're listed
+    pub fn mix(&self, counts: &[(String, usize)]) -> Vec::<&EnemyArchetype> {
+        counts.iter()
+              .flat_map(|(name, count)| {
+                  let archetype = self.get(name);
+                  if archetype.is_none() {
+                      println!("unknown enemy archetype {}", name);
+                  }
+                  archetype.into_iter().flat_map(move |archetype| std::iter::repeat(archetype).take(*count))
+              })
+              .collect()
+    }
+}
+
+#[derive(Default)]
+pub struct EnemyArchetypesLoader;
+
+impl AssetLoader for EnemyArchetypesLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let mut archetypes: EnemyArchetypes = ron::de::from_bytes(bytes)?;
+
+            // models are loaded along with the file so they're ready by the time enemies spawn
+            let mut dependencies = vec![];
+            for archetype in archetypes.archetypes.iter_mut() {
+                if let Some(model) = &archetype.model {
+                    let path = AssetPath::new(PathBuf::from(model), None);
+                    archetype.scene = Some(load_context.get_handle(path.clone()));
+                    dependencies.push(path);
+                }
+            }
+
+            load_context.set_default_asset(LoadedAsset::new(archetypes).with_dependencies(dependencies));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["archetypes.ron"]
+    }
+}
+
+// Tints an enemy's model once its scene has been spawned
+#[derive(Component)]
+pub struct EnemyTint(pub (f32, f32, f32));
+
+fn tint_enemy_models(
+    mut commands: Commands,
+    tints: Query<(Entity, &EnemyTint)>,
+    children: Query<&Children>,
+    mut material_handles: Query<&mut Handle<StandardMaterial>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, tint) in &tints {
+        let mut tinted = false;
+        let mut descendants = vec![entity];
+        while let Some(descendant) = descendants.pop() {
+            if let Ok(mut handle) = material_handles.get_mut(descendant) {
+                // the material is shared with every other enemy so each one gets its own copy
+                if let Some(mut material) = materials.get(&handle).cloned() {
+                    let [r, g, b, a] = material.base_color.as_rgba_f32();
+                    material.base_color = Color::rgba(r * tint.0.0, g * tint.0.1, b * tint.0.2, a);
+                    *handle = materials.add(material);
+                    tinted = true;
+                }
+            }
+
+            if let Ok(children) = children.get(descendant) {
+                descendants.extend(children.iter());
+            }
+        }
+
+        if tinted {
+            commands.entity(entity).remove::<EnemyTint>();
+        }
+    }
+}
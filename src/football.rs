@@ -1,5 +1,5 @@
 use crate::{AppState, game_state, collision, assets::GameAssets, player::Player, ingame,
-LEFT_END, RIGHT_END, LEFT_GOAL, RIGHT_GOAL, BOTTOM_END, TOP_END, enemy, audio::GameAudio};
+LEFT_END, RIGHT_END, LEFT_GOAL, RIGHT_GOAL, BOTTOM_END, TOP_END, enemy, audio::GameAudio, pathing, rng, timestep::{self, FixedEventAppExt}};
 use bevy::prelude::*;
 use rand::Rng;
 use bevy::gltf::Gltf;
@@ -8,9 +8,10 @@ use std::f32::consts::{FRAC_PI_2};
 pub struct FootballPlugin;
 impl Plugin for FootballPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<LaunchFootballEvent>()
-        .add_system_set(
-            SystemSet::on_update(AppState::InGame)
+        app.add_fixed_event::<LaunchFootballEvent>()
+        .add_system_set_to_stage(
+            timestep::FixedUpdateStage,
+            timestep::gameplay()
                 .with_system(check_for_football_pickup)
                 .with_system(handle_launch_football_event)
                 .with_system(move_football),
@@ -34,53 +35,67 @@ fn handle_launch_football_event(
     mut launch_football_event_reader: EventReader<LaunchFootballEvent>,
     game_assets: Res<GameAssets>,
     collidables: collision::Collidables,
+    path_grid: Res<pathing::PathGrid>,
+    players: Query<&Transform, With<Player>>,
     assets_gltf: Res<Assets<Gltf>>,
     mut game_state: ResMut<game_state::GameState>,
     mut spawn_enemies_event_writer: EventWriter<enemy::SpawnEnemiesEvent>,
     mut audio: GameAudio,
+    mut game_rng: ResMut<rng::GameRng>,
 ) {
     for event in launch_football_event_reader.iter() {
-        if let Some(gltf) = assets_gltf.get(&game_assets.football.clone()) {
-            let left_side = Vec3::new(6.976, 0.0, -48.0);
-            let right_side = Vec3::new(6.976, 0.0, 48.0);
-
-            let position = if game_state.touchdown_on_leftside { right_side } else { left_side };
-
-            let mut target = None;
-            let mut rng = rand::thread_rng();
-            let z_buffer = ((RIGHT_GOAL - LEFT_GOAL).abs() * 0.25);
-            let x_buffer = ((TOP_END - BOTTOM_END).abs() * 0.02);
-            let min_z = LEFT_GOAL + z_buffer;
-            let max_z = RIGHT_GOAL - z_buffer;
-            let min_x = BOTTOM_END + x_buffer;
-            let max_x = TOP_END - x_buffer;
-            while target.is_none() {
-                let potential_position = Vec3::new(rng.gen_range(min_x..max_x), 
-                                                   0.0, 
-                                                   rng.gen_range(min_z..max_z));
-                if !collidables.is_in_collidable(&potential_position) {
-                    target = Some(potential_position);
-                }
+        let left_side = Vec3::new(6.976, 0.0, -48.0);
+        let right_side = Vec3::new(6.976, 0.0, 48.0);
+
+        let position = if game_state.touchdown_on_leftside { right_side } else { left_side };
+
+        let mut target = None;
+        let rng = game_rng.stream(rng::RngStream::Football);
+        let z_buffer = ((RIGHT_GOAL - LEFT_GOAL).abs() * 0.25);
+        let x_buffer = ((TOP_END - BOTTOM_END).abs() * 0.02);
+        let min_z = LEFT_GOAL + z_buffer;
+        let max_z = RIGHT_GOAL - z_buffer;
+        let min_x = BOTTOM_END + x_buffer;
+        let max_x = TOP_END - x_buffer;
+        while target.is_none() {
+            let potential_position = Vec3::new(rng.gen_range(min_x..max_x), 
+                                               0.0, 
+                                               rng.gen_range(min_z..max_z));
+            if !collidables.is_in_collidable(&potential_position) {
+                target = Some(potential_position);
             }
+        }
 
-            audio.play_sfx(&game_assets.football_launch);
-            commands.spawn_bundle(SceneBundle {
-                        scene: gltf.scenes[0].clone(),
-                        transform: {
-                            let mut t = Transform::from_scale(Vec3::splat(3.0));
-                            t.translation = position;
-                            t
-                        },
-                        ..default()
-                    })
-                    .insert(Football {
-                        has_landed: false,
-                        target: target.unwrap(),
-                        starting_position: position,
-                        current_movement_time: 0.0,
-                    })
-                    .insert(ingame::CleanupMarker);
+        // a spot that isn't in corn can still be walled off, move it to the closest place
+        // the player can actually walk to
+        let player_start = Vec3::new(0.0, 0.0, if game_state.touchdown_on_leftside { RIGHT_GOAL } else { LEFT_GOAL });
+        let player_position = players.iter()
+                                     .next()
+                                     .map(|transform| transform.translation)
+                                     .unwrap_or(player_start);
+        let target = target.and_then(|target| path_grid.nearest_reachable(player_position, target))
+                           .or(target);
+
+        audio.play_sfx(&game_assets.football_launch);
+        let mut football = commands.spawn_bundle(SpatialBundle::from_transform({
+                                        let mut t = Transform::from_scale(Vec3::splat(3.0));
+                                        t.translation = position;
+                                        t
+                                    }));
+        football.insert(Football {
+                    has_landed: false,
+                    target: target.unwrap(),
+                    starting_position: position,
+                    current_movement_time: 0.0,
+                })
+                .insert(timestep::Interpolated::default())
+                .insert(ingame::CleanupMarker);
+
+        // the model is optional so the football can be simulated headless
+        if let Some(gltf) = assets_gltf.get(&game_assets.football.clone()) {
+            football.insert(gltf.scenes[0].clone());
         }
+
         if !game_state.enemies_spawned {
             spawn_enemies_event_writer.send(enemy::SpawnEnemiesEvent);
             game_state.enemies_spawned = true;
@@ -92,13 +107,17 @@ const FOOTBALL_PICKUP_DISTANCE: f32 = 1.5;
 fn check_for_football_pickup(
     mut commands: Commands,
     footballs: Query<(Entity, &Football, &Transform)>,
-    mut player: Query<(Entity, &mut Player, &Transform)>,
+    mut players: Query<(Entity, &mut Player, &Transform)>,
     mut carried_footballs: Query<(&CarriedFootball, &mut Visibility, &Parent)>,
 ) {
     for (football_entity, football, football_transform) in &footballs {
-        let (player_entity, mut player, player_transform) = player.single_mut();
+        // in versus whoever's closest gets it
+        let closest = players.iter_mut()
+                             .map(|(entity, player, transform)| (entity, player, football_transform.translation.distance(transform.translation)))
+                             .filter(|(_, _, distance)| *distance < FOOTBALL_PICKUP_DISTANCE)
+                             .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
 
-        if football_transform.translation.distance(player_transform.translation) < FOOTBALL_PICKUP_DISTANCE {
+        if let Some((player_entity, mut player, _)) = closest {
             player.has_football = true;
 
             for (_, mut visibility, parent) in &mut carried_footballs {
@@ -114,7 +133,7 @@ fn check_for_football_pickup(
 
 fn move_football(
     mut footballs: Query<(&mut Football, &mut Transform)>,
-    time: Res<Time>,
+    time: Res<timestep::FixedTime>,
 ) {
     let flight_time = 2.0;
     let flight_height = 20.0;
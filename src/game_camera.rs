@@ -33,19 +33,42 @@ impl Default for PanOrbitCamera {
     }
 }
 
+// how far apart runners can get in versus before the camera starts backing off
+const VERSUS_FRAMED_SPREAD: f32 = 20.0;
+
 pub fn follow_player(
     mut cameras: Query<&mut Transform, With<PanOrbitCamera>>,
     players: Query<&Transform, (With<player::Player>, Without<PanOrbitCamera>)>,
     time: Res<Time>,
 ) {
     let camera_speed = 1.2;
+    let runners = players.iter().count();
+    if runners == 0 { return; }
+
+    // halfway between everyone, which is just the runner when there's one
+    let min_z = players.iter().map(|player| player.translation.z).fold(f32::MAX, f32::min);
+    let max_z = players.iter().map(|player| player.translation.z).fold(f32::MIN, f32::max);
+    let focus_z = (min_z + max_z) / 2.0;
+
     for mut camera_transform in cameras.iter_mut() {
-        for player_transform in players.iter() {
-            camera_transform.translation.z += 
-                (player_transform.translation.z - camera_transform.translation.z)
+        camera_transform.translation.z += 
+            (focus_z - camera_transform.translation.z)
+            * camera_speed
+            * time.delta_seconds();
+
+        // backs away along its view as the runners spread out so both stay on screen
+        if runners > 1 {
+            let pull_back = (max_z - min_z - VERSUS_FRAMED_SPREAD).max(0.0) * 0.6;
+            let target = Vec3::new(INGAME_CAMERA_X, INGAME_CAMERA_Y, camera_transform.translation.z)
+                       + camera_transform.back() * pull_back;
+            camera_transform.translation.x += 
+                (target.x - camera_transform.translation.x)
+                * camera_speed
+                * time.delta_seconds();
+            camera_transform.translation.y += 
+                (target.y - camera_transform.translation.y)
                 * camera_speed
                 * time.delta_seconds();
-
         }
     }
 }
@@ -1,202 +1,222 @@
+use crate::{controls, game_state, localization::Localization, menus, assets::GameAssets, ui::text_size};
+use bevy::input::gamepad::Gamepads;
+use bevy::input::InputSystem;
 use bevy::prelude::*;
-use std::collections::HashMap;
+
+// Each runner keeps the pad it was given until it's pulled out, pads are handed out in the order
+// they're plugged in and a runner that loses theirs gets the next spare one. Runner one's pad is
+// also the one for the menus and for a single runner, runner two's stands in when there isn't one.
+// Buttons go through the leafwing input maps, which are pointed at those pads by
+// controls::apply_controls, and so does the menus stepping on the left stick. All that's kept
+// here is the runners' left sticks, which they move by.
 
 pub struct GameControllerPlugin;
 impl Plugin for GameControllerPlugin {
     fn build(&self, app: &mut App) {
-        app//.add_system(gamepad_connections)
-            .insert_resource(GameController::default());
-            //.add_system(store_controller_inputs);
+        app.insert_resource(GameController::default())
+            .init_resource::<RunnerGamepads>()
+            .add_event::<ControllerEvent>()
+            .add_system_to_stage(CoreStage::PreUpdate, gamepad_connections.after(InputSystem))
+            .add_system(store_controller_inputs)
+            .add_system(show_toast)
+            .add_system(hide_toast);
     }
 }
 
+// indexed by Player::number, None for a runner without a pad
 #[derive(Default)]
-pub struct GameController {
-    pub players: Vec<Gamepad>,
-    pub pressed: HashMap<usize, Vec<GameButton>>,
-    pub just_pressed: HashMap<usize, Vec<GameButton>>,
-}
+pub struct RunnerGamepads([Option<Gamepad>; 2]);
 
-impl GameController {
-    fn clear_presses(&mut self) {
-        self.pressed = HashMap::<usize, Vec<GameButton>>::new();
-        self.just_pressed = HashMap::<usize, Vec<GameButton>>::new();
+impl RunnerGamepads {
+    // the menus and a single runner
+    pub fn first(&self) -> Option<Gamepad> {
+        self.0[0].or(self.0[1])
     }
-}
 
-pub fn clear_presses(mut controllers: ResMut<GameController>) {
-    controllers.clear_presses();
-}
-
-pub fn store_controller_inputs(
-    axes: Res<Axis<GamepadAxis>>,
-    buttons: Res<Input<GamepadButton>>,
-    mut controllers: ResMut<GameController>,
-) {
-    let mut pressed: HashMap<usize, Vec<GameButton>> = HashMap::new();
-    let mut just_pressed: HashMap<usize, Vec<GameButton>> = HashMap::new();
-    for gamepad in controllers.players.iter() {
-        let mut pressed_buttons = vec![];
-        let gamepad = *gamepad;
-
-        // The joysticks are represented using a separate axis for X and Y
-        let axis_lx = GamepadAxis{ gamepad: gamepad, axis_type: GamepadAxisType::LeftStickX };
-        let axis_ly = GamepadAxis{ gamepad: gamepad, axis_type: GamepadAxisType::LeftStickY };
-
-        if let (Some(x), Some(y)) = (axes.get(axis_lx), axes.get(axis_ly)) {
-            // combine X and Y into one vector
-            let left_stick_pos = Vec2::new(x, y);
-
-            // implement a dead-zone to ignore small inputs
-            if left_stick_pos.length() > 0.2 {
-                // do something with the position of the left stick
-                if x > 0.0 {
-                    pressed_buttons.push(GameButton::Right);
-                }
-                if x < 0.0 {
-                    pressed_buttons.push(GameButton::Left);
-                }
-                if y > 0.0 {
-                    pressed_buttons.push(GameButton::Up);
-                }
-                if y < 0.0 {
-                    pressed_buttons.push(GameButton::Down);
-                }
-            }
+    // only versus keeps the runners to their own pads
+    pub fn runner(&self, number: usize, versus: bool) -> Option<Gamepad> {
+        if versus {
+            self.0.get(number).copied().flatten()
+        } else {
+            self.first()
         }
+    }
 
-        // The joysticks are represented using a separate axis for X and Y
-        //      let axis_rx = GamepadAxis(gamepad, GamepadAxisType::RightStickX);
-        //      let axis_ry = GamepadAxis(gamepad, GamepadAxisType::RightStickY);
-
-        //      if let (Some(x), Some(y)) = (axes.get(axis_rx), axes.get(axis_ry)) {
-        //          // combine X and Y into one vector
-        //          let left_stick_pos = Vec2::new(x, y);
-
-        //          // implement a dead-zone to ignore small inputs
-        //          if left_stick_pos.length() > 0.1 {
-        //              // do something with the position of the left stick
-        //              if x > 0.0 {
-        //                  pressed_buttons.push(GameButton::ActionRight);
-        //              }
-        //              if x < 0.0 {
-        //                  pressed_buttons.push(GameButton::ActionLeft);
-        //              }
-        //              if y > 0.0 {
-        //                  pressed_buttons.push(GameButton::ActionUp);
-        //              }
-        //              if y < 0.0 {
-        //                  pressed_buttons.push(GameButton::ActionDown);
-        //              }
-        //          }
-        //      }
-
-        // Dpad isn't an axis anymore in bevy 0.8
-//      let axis_dx = GamepadAxis(gamepad, GamepadAxisType::DPadX);
-//      let axis_dy = GamepadAxis(gamepad, GamepadAxisType::DPadY);
-
-//      if let (Some(x), Some(y)) = (axes.get(axis_dx), axes.get(axis_dy)) {
-//          // combine X and Y into one vector
-//          let left_stick_pos = Vec2::new(x, y);
-
-//          // implement a dead-zone to ignore small inputs
-//          if left_stick_pos.length() > 0.2 {
-//              // do something with the position of the left stick
-//              if x > 0.0 {
-//                  pressed_buttons.push(GameButton::Right);
-//              }
-//              if x < 0.0 {
-//                  pressed_buttons.push(GameButton::Left);
-//              }
-//              if y > 0.0 {
-//                  pressed_buttons.push(GameButton::Up);
-//              }
-//              if y < 0.0 {
-//                  pressed_buttons.push(GameButton::Down);
-//              }
-//          }
-//      }
-
-        let dpad_up = GamepadButton { gamepad: gamepad, button_type: GamepadButtonType::DPadUp };
-        let dpad_down = GamepadButton { gamepad: gamepad, button_type: GamepadButtonType::DPadDown };
-        let dpad_left = GamepadButton { gamepad: gamepad, button_type: GamepadButtonType::DPadLeft };
-        let dpad_right = GamepadButton { gamepad: gamepad, button_type: GamepadButtonType::DPadRight };
-
-        if buttons.pressed(dpad_up) {
-            pressed_buttons.push(GameButton::Up);
-        }
+    fn is_assigned(&self, gamepad: Gamepad) -> bool {
+        self.0.contains(&Some(gamepad))
+    }
+}
 
-        if buttons.pressed(dpad_down) {
-            pressed_buttons.push(GameButton::Down);
-        }
+pub enum ControllerEvent {
+    Connected(Gamepad),
+    // only sent for a pad a runner was using
+    Disconnected(Gamepad),
+}
 
-        if buttons.pressed(dpad_left) {
-            pressed_buttons.push(GameButton::Left);
-        }
+#[derive(Default)]
+pub struct GameController {
+    // left stick of runner one's pad after the deadzone and curve
+    pub stick: Vec2,
+    // the same for runner two's pad
+    pub second_stick: Vec2,
+}
 
-        if buttons.pressed(dpad_right) {
-            pressed_buttons.push(GameButton::Right);
-        }
+fn left_stick(axes: &Axis<GamepadAxis>, gamepad: Option<Gamepad>, controls: &controls::Controls) -> Vec2 {
+    let gamepad = match gamepad {
+        Some(gamepad) => gamepad,
+        None => return Vec2::ZERO,
+    };
 
-        let south = GamepadButton { gamepad: gamepad, button_type: GamepadButtonType::South };
-        let east = GamepadButton { gamepad: gamepad, button_type: GamepadButtonType::East };
-        let west = GamepadButton { gamepad: gamepad, button_type: GamepadButtonType::West };
-        let north = GamepadButton { gamepad: gamepad, button_type: GamepadButtonType::North };
+    // The joysticks are represented using a separate axis for X and Y
+    let axis_lx = GamepadAxis{ gamepad: gamepad, axis_type: GamepadAxisType::LeftStickX };
+    let axis_ly = GamepadAxis{ gamepad: gamepad, axis_type: GamepadAxisType::LeftStickY };
 
-        if buttons.pressed(south) {
-            pressed_buttons.push(GameButton::ActionDown);
-        }
-        if buttons.pressed(north) {
-            pressed_buttons.push(GameButton::ActionUp);
-        }
-        if buttons.pressed(west) {
-            pressed_buttons.push(GameButton::ActionLeft);
-        }
-        if buttons.pressed(east) {
-            pressed_buttons.push(GameButton::ActionRight);
-        }
-
-        let start_button = GamepadButton { gamepad: gamepad, button_type: GamepadButtonType::Start };
-        if buttons.pressed(start_button) {
-            pressed_buttons.push(GameButton::Start);
-        }
+    match (axes.get(axis_lx), axes.get(axis_ly)) {
+        // combine X and Y into one vector
+        (Some(x), Some(y)) => controls.stick.apply(Vec2::new(x, y)),
+        _ => Vec2::ZERO,
+    }
+}
 
-        let game_id = gamepad.id;
-        let mut just_pressed_buttons = pressed_buttons.clone();
-        just_pressed_buttons.retain(|button| {
-            !controllers.pressed.contains_key(&game_id)
-                || !controllers.pressed[&game_id].contains(button)
-        });
+pub fn store_controller_inputs(
+    axes: Res<Axis<GamepadAxis>>,
+    runner_gamepads: Res<RunnerGamepads>,
+    mut controllers: ResMut<GameController>,
+    controls: Res<controls::Controls>,
+    game_state: Res<game_state::GameState>,
+) {
+    let stick = left_stick(&axes, runner_gamepads.runner(0, game_state.versus), &controls);
+    let second_stick = left_stick(&axes, runner_gamepads.runner(1, game_state.versus), &controls);
 
-        pressed.insert(game_id, pressed_buttons);
-        just_pressed.insert(game_id, just_pressed_buttons);
+    // only written when something moved so anything watching for changes isn't woken every frame
+    if controllers.stick != stick || controllers.second_stick != second_stick {
+        controllers.stick = stick;
+        controllers.second_stick = second_stick;
     }
-
-    controllers.pressed = pressed;
-    controllers.just_pressed = just_pressed;
 }
 
 pub fn gamepad_connections(
     mut gamepad_evr: EventReader<GamepadEvent>,
-    mut controllers: ResMut<GameController>,
+    gamepads: Res<Gamepads>,
+    mut runner_gamepads: ResMut<RunnerGamepads>,
+    mut controller_event_writer: EventWriter<ControllerEvent>,
+    game_state: Res<game_state::GameState>,
 ) {
     for GamepadEvent { gamepad, event_type } in gamepad_evr.iter() {
-        if *event_type == GamepadEventType::Connected {
-            println!("New gamepad connected with ID: {:?}", gamepad);
-            controllers.players.push(*gamepad);
+        match event_type {
+            GamepadEventType::Connected => {
+                println!("New gamepad connected with ID: {:?}", gamepad);
+                if !runner_gamepads.is_assigned(*gamepad) {
+                    if let Some(slot) = runner_gamepads.0.iter_mut().find(|slot| slot.is_none()) {
+                        *slot = Some(*gamepad);
+                    }
+                }
+                controller_event_writer.send(ControllerEvent::Connected(*gamepad));
+            },
+            GamepadEventType::Disconnected => {
+                println!("Gamepad disconnected with ID: {:?}", gamepad);
+                let number = match runner_gamepads.0.iter().position(|slot| *slot == Some(*gamepad)) {
+                    Some(number) => number,
+                    None => continue,
+                };
+                let in_use = runner_gamepads.runner(number, game_state.versus) == Some(*gamepad);
+
+                // the other runner's pad stays where it is, only a spare one takes over
+                runner_gamepads.0[number] = None;
+                let spare = gamepads.iter()
+                                    .copied()
+                                    .find(|other| other != gamepad && !runner_gamepads.is_assigned(*other));
+                runner_gamepads.0[number] = spare;
+
+                if in_use {
+                    controller_event_writer.send(ControllerEvent::Disconnected(*gamepad));
+                }
+            },
+            _ => (),
         }
     }
 }
 
-#[derive(PartialEq, Debug, Copy, Clone)]
-pub enum GameButton {
-    Up,
-    Down,
-    Left,
-    Right,
-    ActionUp,
-    ActionLeft,
-    ActionRight,
-    ActionDown,
-    Start,
+#[derive(Component)]
+struct Toast {
+    remaining: f32,
+}
+
+// a line along the bottom of the screen over whatever is showing, it isn't cleaned up
+// with any state so it stays up across menus and rounds until it runs out
+fn show_toast(
+    mut commands: Commands,
+    mut controller_event_reader: EventReader<ControllerEvent>,
+    toasts: Query<Entity, With<Toast>>,
+    game_assets: Res<GameAssets>,
+    localization: Res<Localization>,
+    text_scaler: text_size::TextScaler,
+) {
+    let key = match controller_event_reader.iter().last() {
+        Some(ControllerEvent::Connected(_)) => "controller.connected",
+        Some(ControllerEvent::Disconnected(_)) => "controller.disconnected",
+        None => return,
+    };
+
+    for entity in &toasts {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(8.0)),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Percent(0.0),
+                    bottom: Val::Percent(4.0),
+                    ..Default::default()
+                },
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            color: Color::NONE.into(),
+            ..Default::default()
+        })
+        .insert(Toast { remaining: 2.5 })
+        .with_children(|parent| {
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        padding: UiRect::all(Val::Percent(1.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    color: menus::OVERLAY_BACKGROUND.into(),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    parent.spawn_bundle(TextBundle {
+                        text: Text::from_section(
+                            localization.get(key),
+                            TextStyle {
+                                font: localization.font(&game_assets),
+                                font_size: text_scaler.scale(menus::BUTTON_LABEL_FONT_SIZE),
+                                color: Color::WHITE,
+                            }
+                        ),
+                        ..Default::default()
+                    });
+                });
+        });
+}
+
+fn hide_toast(
+    mut commands: Commands,
+    mut toasts: Query<(Entity, &mut Toast)>,
+    time: Res<Time>,
+) {
+    for (entity, mut toast) in &mut toasts {
+        toast.remaining -= time.delta_seconds();
+        if toast.remaining <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
 }
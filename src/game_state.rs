@@ -1,13 +1,14 @@
-use crate::{AppState, football, player};
+use crate::{AppState, football, player, timestep::{self, FixedEventAppExt}};
 use bevy::prelude::*;
 
 pub struct GameStatePlugin;
 impl Plugin for GameStatePlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(GameState::default())
-            .add_event::<TouchdownEvent>()
-            .add_system_set(
-                SystemSet::on_update(AppState::InGame)
+            .add_fixed_event::<TouchdownEvent>()
+            .add_system_set_to_stage(
+                timestep::FixedUpdateStage,
+                timestep::gameplay()
                     .with_system(handle_touchdown_event.after(player::check_for_touchdown))
                     .with_system(placeholder)
             );
@@ -18,13 +19,20 @@ impl Plugin for GameStatePlugin {
 pub struct LevelOverCleanupMarker;
 
 pub struct GameState {
+    // points in the round being played, finished rounds are moved into round_scores
     pub score: usize,
+    pub round_scores: Vec::<usize>,
     pub shadows_on: bool,
     pub graphics_high: bool, 
     pub maze_size: f32,
     pub is_latest: bool,
     pub touchdown_on_leftside: bool,
-    pub attached_enemies: usize,
+    // two runners on one field racing for the same football
+    pub versus: bool,
+    // each runner's share of score in versus, by Player::number
+    pub player_scores: [usize; 2],
+    // the same across every finished round
+    pub player_totals: [usize; 2],
     pub title_screen_cooldown: f32,
     pub enemies_spawned: bool,
     pub corn_spawned: bool,
@@ -36,9 +44,12 @@ impl GameState {
     pub fn initialize(graphics: bool, shadows_on: bool, game_version: bool) -> Self {
         GameState {
             score: 0,
+            round_scores: vec![],
             shadows_on: shadows_on, 
             graphics_high: graphics, 
-            attached_enemies: 0, 
+            versus: false,
+            player_scores: [0; 2],
+            player_totals: [0; 2],
             is_latest: game_version, 
             maze_size: 80.0,
             touchdown_on_leftside: false,
@@ -55,9 +66,12 @@ impl Default for GameState {
     fn default() -> Self {
         GameState {
             score: 0,
+            round_scores: vec![],
             shadows_on: true,
             graphics_high: true, 
-            attached_enemies: 0, 
+            versus: false,
+            player_scores: [0; 2],
+            player_totals: [0; 2],
             enemies_spawned: false,
             is_latest: true,
             maze_size: 80.0,
@@ -70,7 +84,22 @@ impl Default for GameState {
     }
 }
 
-pub struct TouchdownEvent;
+impl GameState {
+    // the round's points go on the run's record, the round's own counts are zeroed by whatever
+    // sets up the next one
+    pub fn bank_round(&mut self) {
+        let round_score = self.score;
+        self.round_scores.push(round_score);
+        for (total, score) in self.player_totals.iter_mut().zip(self.player_scores) {
+            *total += score;
+        }
+    }
+}
+
+pub struct TouchdownEvent {
+    // Player::number of whoever carried it in
+    pub player: usize,
+}
 
 fn handle_touchdown_event(
     mut touchdown_event_reader: EventReader<TouchdownEvent>,
@@ -79,6 +108,7 @@ fn handle_touchdown_event(
 ) {
     for event in touchdown_event_reader.iter() {
         game_state.score += 100;
+        game_state.player_scores[event.player] += 100;
         game_state.touchdown_on_leftside = !game_state.touchdown_on_leftside;
         football_launch_event_writer.send(football::LaunchFootballEvent);
     }
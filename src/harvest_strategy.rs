@@ -0,0 +1,260 @@
+use bevy::prelude::*;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+// How a combine decides which lane to cut next. Combines always drive passes along z and
+// shift over along x between them (see combine::harvest_corn), a strategy only picks the
+// x coordinate of the next pass once the current one is done.
+
+// about how much of the field one pass of the blade clears
+pub const LANE_WIDTH: f32 = 5.0;
+// how often the hunter goes for the player's lane instead of a random one
+const HUNTER_BIAS: f64 = 0.75;
+
+pub struct HarvestContext<'a> {
+    pub combine_position: Vec3,
+    pub unharvested: &'a [Vec3],
+    pub player_position: Option<Vec3>,
+    pub rng: &'a mut StdRng,
+}
+
+pub trait HarvestStrategy: Send + Sync {
+    // the x coordinate of the next lane, None when there's nothing left to cut
+    fn next_lane(&mut self, context: &mut HarvestContext) -> Option<f32>;
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+pub enum HarvestPattern {
+    // a random unharvested stalk's lane every time
+    Random,
+    // back and forth across the field one lane over each pass
+    Serpentine,
+    // the outermost lanes first, alternating sides and closing in on the middle
+    InwardSpiral,
+    // the closest lane for how much corn is left in it
+    NearestCluster,
+    // lanes near the player, with the odd random one
+    Hunter,
+}
+
+impl HarvestPattern {
+    pub fn strategy(&self) -> Box<dyn HarvestStrategy> {
+        match self {
+            HarvestPattern::Random => Box::new(RandomLanes),
+            HarvestPattern::Serpentine => Box::new(Serpentine { last_lane: None, upward: true }),
+            HarvestPattern::InwardSpiral => Box::new(InwardSpiral { from_bottom: true }),
+            HarvestPattern::NearestCluster => Box::new(NearestCluster),
+            HarvestPattern::Hunter => Box::new(Hunter),
+        }
+    }
+}
+
+pub struct RandomLanes;
+impl HarvestStrategy for RandomLanes {
+    fn next_lane(&mut self, context: &mut HarvestContext) -> Option<f32> {
+        context.unharvested.choose(&mut *context.rng).map(|corn| corn.x)
+    }
+}
+
+pub struct Serpentine {
+    last_lane: Option<f32>,
+    upward: bool,
+}
+
+impl HarvestStrategy for Serpentine {
+    fn next_lane(&mut self, context: &mut HarvestContext) -> Option<f32> {
+        let last_lane = self.last_lane.unwrap_or(context.combine_position.x);
+
+        // try the next lane on in the current direction, turn around at the edge of the corn
+        for _ in 0..2 {
+            let xs = context.unharvested.iter().map(|corn| corn.x);
+            let next = if self.upward {
+                xs.filter(|x| *x > last_lane + LANE_WIDTH * 0.5)
+                  .min_by(|a, b| a.total_cmp(b))
+                  .map(|x| x + LANE_WIDTH * 0.5)
+            } else {
+                xs.filter(|x| *x < last_lane - LANE_WIDTH * 0.5)
+                  .max_by(|a, b| a.total_cmp(b))
+                  .map(|x| x - LANE_WIDTH * 0.5)
+            };
+
+            if next.is_some() {
+                self.last_lane = next;
+                return next;
+            }
+            self.upward = !self.upward;
+        }
+
+        // all that's left is in the lane it just cut
+        context.unharvested.first().map(|corn| corn.x)
+    }
+}
+
+pub struct InwardSpiral {
+    from_bottom: bool,
+}
+
+impl HarvestStrategy for InwardSpiral {
+    fn next_lane(&mut self, context: &mut HarvestContext) -> Option<f32> {
+        let xs = context.unharvested.iter().map(|corn| corn.x);
+        let lane = if self.from_bottom {
+            xs.min_by(|a, b| a.total_cmp(b)).map(|x| x + LANE_WIDTH * 0.5)
+        } else {
+            xs.max_by(|a, b| a.total_cmp(b)).map(|x| x - LANE_WIDTH * 0.5)
+        };
+
+        self.from_bottom = !self.from_bottom;
+        lane
+    }
+}
+
+// Unharvested corn is grouped into lane wide strips along x and the strip picked is the one
+// closest to the combine for how much corn is in it, so a lone leftover stalk doesn't drag
+// it across the field past a lane that's still full.
+pub struct NearestCluster;
+impl HarvestStrategy for NearestCluster {
+    fn next_lane(&mut self, context: &mut HarvestContext) -> Option<f32> {
+        // ordered so ties always go the same way and replays stay in sync
+        let mut lanes = BTreeMap::<i32, usize>::new();
+        for corn in context.unharvested {
+            *lanes.entry((corn.x / LANE_WIDTH).floor() as i32).or_default() += 1;
+        }
+
+        // a lane's width is added on so the one the combine is already in still has to have
+        // corn in it to win
+        let position = context.combine_position.x;
+        let cost = |x: f32, count: usize| ((x - position).abs() + LANE_WIDTH) / count as f32;
+
+        lanes.into_iter()
+             .map(|(lane, count)| ((lane as f32 + 0.5) * LANE_WIDTH, count))
+             .min_by(|(a, a_count), (b, b_count)| cost(*a, *a_count).total_cmp(&cost(*b, *b_count)))
+             .map(|(x, _)| x)
+    }
+}
+
+pub struct Hunter;
+impl HarvestStrategy for Hunter {
+    fn next_lane(&mut self, context: &mut HarvestContext) -> Option<f32> {
+        match context.player_position {
+            Some(player) if context.rng.gen_bool(HUNTER_BIAS) => {
+                context.unharvested
+                       .iter()
+                       .min_by(|a, b| (a.x - player.x).abs().total_cmp(&(b.x - player.x).abs()))
+                       .map(|corn| corn.x)
+            },
+            _ => RandomLanes.next_lane(context),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn nearest_cluster_skips_a_lone_stalk_for_a_full_lane() {
+        let mut unharvested = vec![Vec3::new(1.0, 0.0, 0.0)];
+        unharvested.extend((0..20).map(|i| Vec3::new(11.0 + (i % 3) as f32, 0.0, i as f32)));
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut context = HarvestContext {
+            combine_position: Vec3::new(-10.0, 0.0, 0.0),
+            unharvested: &unharvested,
+            player_position: None,
+            rng: &mut rng,
+        };
+
+        assert_eq!(NearestCluster.next_lane(&mut context), Some(12.5));
+    }
+
+    #[test]
+    fn nearest_cluster_takes_the_closer_of_two_full_lanes() {
+        let unharvested = (0..10).map(|i| Vec3::new(1.0, 0.0, i as f32))
+                                 .chain((0..10).map(|i| Vec3::new(21.0, 0.0, i as f32)))
+                                 .collect::<Vec::<_>>();
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut context = HarvestContext {
+            combine_position: Vec3::new(-10.0, 0.0, 0.0),
+            unharvested: &unharvested,
+            player_position: None,
+            rng: &mut rng,
+        };
+
+        assert_eq!(NearestCluster.next_lane(&mut context), Some(2.5));
+    }
+
+    #[test]
+    fn nearest_cluster_has_nothing_to_cut_in_a_bare_field() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut context = HarvestContext {
+            combine_position: Vec3::ZERO,
+            unharvested: &[],
+            player_position: None,
+            rng: &mut rng,
+        };
+
+        assert_eq!(NearestCluster.next_lane(&mut context), None);
+    }
+
+    #[test]
+    fn serpentine_turns_around_at_the_edge_of_the_corn() {
+        let unharvested = [0.0, 6.0, 12.0].map(|x| Vec3::new(x, 0.0, 0.0));
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut context = HarvestContext {
+            combine_position: Vec3::new(-5.0, 0.0, 0.0),
+            unharvested: &unharvested,
+            player_position: None,
+            rng: &mut rng,
+        };
+
+        let mut serpentine = Serpentine { last_lane: None, upward: true };
+        let lanes = (0..4).map(|_| serpentine.next_lane(&mut context))
+                          .collect::<Vec::<_>>();
+
+        assert_eq!(lanes, vec![Some(2.5), Some(8.5), Some(14.5), Some(3.5)]);
+    }
+
+    #[test]
+    fn inward_spiral_alternates_sides() {
+        let unharvested = (0..5).map(|i| Vec3::new(i as f32 * 5.0, 0.0, 0.0))
+                                .collect::<Vec::<_>>();
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut context = HarvestContext {
+            combine_position: Vec3::ZERO,
+            unharvested: &unharvested,
+            player_position: None,
+            rng: &mut rng,
+        };
+
+        let mut spiral = InwardSpiral { from_bottom: true };
+        let lanes = (0..4).map(|_| spiral.next_lane(&mut context))
+                          .collect::<Vec::<_>>();
+
+        assert_eq!(lanes, vec![Some(2.5), Some(17.5), Some(2.5), Some(17.5)]);
+    }
+
+    #[test]
+    fn hunter_mostly_takes_the_players_lane() {
+        let unharvested = (0..5).map(|i| Vec3::new(i as f32 * 5.0, 0.0, 0.0))
+                                .collect::<Vec::<_>>();
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut context = HarvestContext {
+            combine_position: Vec3::ZERO,
+            unharvested: &unharvested,
+            player_position: Some(Vec3::new(12.0, 0.0, 0.0)),
+            rng: &mut rng,
+        };
+
+        let players_lane = (0..100).filter(|_| Hunter.next_lane(&mut context) == Some(10.0))
+                                   .count();
+
+        // 75% chasing plus the odd random pick that lands there anyway
+        assert!(players_lane > 60, "only took the player's lane {} times", players_lane);
+    }
+}
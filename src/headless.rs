@@ -0,0 +1,352 @@
+use crate::{
+    AppState, assets, collision, combine, commentary, component_adder::{AnimationLink, ComponentAdder}, cutscene,
+    enemy, enemy_archetypes, football, game_controller, game_state, ingame, maze, maze_generator, pathing, player, rng, rounds, timestep::{self, FixedEventAppExt},
+    LEFT_GOAL,
+};
+use bevy::asset::AssetPlugin;
+use bevy::gltf::Gltf;
+use bevy::hierarchy::HierarchyPlugin;
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::render::primitives::Aabb;
+use bevy::time::TimePlugin;
+use bevy::transform::TransformPlugin;
+use std::f32::consts::TAU;
+use std::time::{Duration, Instant};
+
+// Runs the InGame gameplay plugins on MinimalPlugins with no window, GPU or audio.
+// Gameplay entities are spawned without their gltf scenes so rounds can be simulated
+// on machines without a display and the resulting GameState inspected afterwards.
+
+pub struct HeadlessSettings {
+    pub step: f32,
+    pub maze: Option<collision::WorldAabb>,
+}
+
+impl Default for HeadlessSettings {
+    fn default() -> Self {
+        HeadlessSettings {
+            step: 1.0 / 60.0,
+            maze: Some(maze::field_area()),
+        }
+    }
+}
+
+pub struct HeadlessPlugin;
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HeadlessClock>()
+            .add_system_to_stage(CoreStage::First, advance_clock)
+            .add_system_set(
+                SystemSet::on_enter(AppState::InGame)
+                    .with_system(setup)
+            );
+    }
+}
+
+pub fn build_app(settings: HeadlessSettings) -> App {
+    let mut app = App::new();
+    app.add_plugins_with(MinimalPlugins, |group| group.disable::<TimePlugin>())
+        .add_plugin(TransformPlugin)
+        .add_plugin(HierarchyPlugin)
+        .add_plugin(InputPlugin)
+        .add_plugin(AssetPlugin)
+        .add_asset::<Gltf>()
+        .add_asset::<Mesh>()
+        .add_asset::<StandardMaterial>()
+        .add_asset::<AnimationClip>()
+        .insert_resource(Time::default())
+        .insert_resource(settings)
+        .insert_resource(assets::GameAssets::default())
+        .insert_resource(game_controller::GameController::default())
+        .insert_resource(cutscene::CutsceneState::default())
+        .insert_resource(ComponentAdder::default())
+        .add_plugin(timestep::TimestepPlugin)
+        .add_plugin(collision::CollisionPlugin)
+        .add_plugin(pathing::PathingPlugin)
+        .add_plugin(rng::RngPlugin)
+        .add_plugin(player::PlayerPlugin)
+        .add_plugin(enemy::EnemyPlugin)
+        .add_plugin(enemy_archetypes::EnemyArchetypesPlugin)
+        .add_plugin(rounds::RoundsPlugin)
+        .add_plugin(combine::CombinePlugin)
+        .add_plugin(football::FootballPlugin)
+        .add_plugin(game_state::GameStatePlugin)
+        .add_plugin(maze::MazePlugin)
+        // combines and enemies still send these, there's just nobody in the booth to hear them
+        .add_fixed_event::<commentary::CommentaryEvent>()
+        .add_plugin(HeadlessPlugin)
+        .add_state(AppState::InGame);
+
+    app
+}
+
+// advances the app by the given amount of simulated time
+pub fn simulate(app: &mut App, seconds: f32) {
+    let step = app.world.resource::<HeadlessSettings>().step;
+    let frames = (seconds / step).ceil() as usize;
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+pub fn run() {
+    let mut app = build_app(HeadlessSettings::default());
+    let simulated_seconds = 120.0;
+    let step = app.world.resource::<HeadlessSettings>().step;
+    let mut elapsed = 0.0;
+
+    while elapsed < simulated_seconds {
+        simulate(&mut app, step);
+        elapsed += step;
+
+        // nothing plays cutscenes headless so a requested one means the round is over
+        if app.world.resource::<cutscene::CutsceneState>().current.is_some() {
+            break;
+        }
+    }
+
+    let attached_enemies: usize = app.world.query::<&player::Player>()
+                                           .iter(&app.world)
+                                           .map(|player| player.attached_enemies)
+                                           .sum();
+    let game_state = app.world.resource::<game_state::GameState>();
+    println!("simulated {:.1}s: score {} deaths {} attached enemies {}",
+             elapsed, game_state.score, game_state.death_count, attached_enemies);
+
+    let maze_report = app.world.resource::<pathing::MazeReport>();
+    println!("maze {}: {} of {} open cells reachable from the left goal",
+             if maze_report.solvable { "solvable" } else { "not solvable" },
+             maze_report.reachable_from_left, maze_report.open_cells);
+}
+
+struct HeadlessClock {
+    now: Instant,
+}
+
+impl Default for HeadlessClock {
+    fn default() -> Self {
+        HeadlessClock {
+            now: Instant::now(),
+        }
+    }
+}
+
+// steps time by a fixed amount every frame regardless of how long the frame actually took
+fn advance_clock(
+    mut clock: ResMut<HeadlessClock>,
+    mut time: ResMut<Time>,
+    settings: Res<HeadlessSettings>,
+) {
+    clock.now += Duration::from_secs_f32(settings.step);
+    time.update_with_instant(clock.now);
+}
+
+fn setup(
+    mut commands: Commands,
+    settings: Res<HeadlessSettings>,
+    game_state: Res<game_state::GameState>,
+    mut game_rng: ResMut<rng::GameRng>,
+    maze_settings: Res<maze_generator::MazeSettings>,
+    rounds: Res<rounds::Rounds>,
+    mut football_launch_event_writer: EventWriter<football::LaunchFootballEvent>,
+) {
+    commands.spawn_bundle(SpatialBundle::from_transform({
+                let mut t = Transform::from_xyz(0.0, 0.0, LEFT_GOAL);
+                t.rotate_y(TAU * 0.75);
+                t
+            }))
+            .insert_bundle(player::PlayerBundle::new(0, game_rng.stream(rng::RngStream::Player)))
+            .insert(AnimationLink {
+                entity: None
+            })
+            .with_children(|parent| {
+                parent.spawn_bundle(SpatialBundle {
+                          visibility: Visibility { is_visible: false },
+                          ..default()
+                      })
+                      .insert(football::CarriedFootball);
+            })
+            .insert(ingame::CleanupMarker);
+
+    let round = rounds.get(game_state.current_round);
+    let lanes = combine::starting_lanes(round.combines.len(), &[], game_rng.stream(rng::RngStream::Combine));
+    for (definition, lane) in round.combines.iter().zip(lanes) {
+        commands.spawn_bundle(SpatialBundle::from_transform({
+                    let mut t = Transform::from_xyz(lane, 0.0, game_state.maze_size / 2.0);
+                    t.rotate_y(TAU * 0.25);
+                    t
+                }))
+                .insert(combine::Combine::create(game_state.is_latest, definition, lane))
+                .insert(timestep::Interpolated::default())
+                .insert(AnimationLink {
+                    entity: None
+                })
+                .with_children(|parent| {
+                    // stands in for the combine_blade node in combine.glb, the header sits in front
+                    // of the combine along its local x axis
+                    parent.spawn_bundle(SpatialBundle::from_transform(Transform::from_xyz(2.5, 0.0, 0.0)))
+                          .insert(Aabb::from_min_max(Vec3::new(-0.5, 0.0, -3.0), Vec3::new(0.5, 2.0, 3.0)))
+                          .insert(combine::CombineBlade::default());
+                })
+                .insert(ingame::CleanupMarker);
+    }
+
+    if let Some(aabb) = settings.maze {
+        // the authored layouts are in maze glbs that aren't loaded here, so every round gets a
+        // generated one in their place
+        if !game_state.corn_spawned {
+            commands.spawn_bundle(SpatialBundle::default())
+                    .insert(maze::generated_marker(aabb, &game_state, &maze_settings, &mut game_rng))
+                    .insert(ingame::CleanupMarker);
+        }
+    }
+
+    football_launch_event_writer.send(football::LaunchFootballEvent);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RIGHT_GOAL;
+    use bevy::hierarchy::despawn_with_children_recursive;
+
+    // a round with no corn, combines, football or enemies, just the runner standing at the left goal
+    fn empty_round() -> App {
+        let mut app = build_app(HeadlessSettings {
+            maze: None,
+            ..default()
+        });
+        // long enough for the opening football to be launched and the enemies it brings spawned
+        simulate(&mut app, 0.5);
+
+        let strays = app.world
+                        .query_filtered::<Entity, Or<(With<combine::Combine>, With<enemy::Enemy>, With<football::Football>)>>()
+                        .iter(&app.world)
+                        .collect::<Vec::<_>>();
+        for entity in strays {
+            despawn_with_children_recursive(&mut app.world, entity);
+        }
+
+        app
+    }
+
+    fn runner(app: &mut App) -> Entity {
+        app.world.query_filtered::<Entity, With<player::Player>>().single(&app.world)
+    }
+
+    fn runner_state(app: &mut App) -> &player::Player {
+        let runner = runner(app);
+        app.world.get::<player::Player>(runner).unwrap()
+    }
+
+    // the runner's last simulated transform is put back at the start of every step so that
+    // has to be forgotten too
+    fn move_runner(app: &mut App, translation: Vec3) {
+        let runner = runner(app);
+        let mut entity = app.world.entity_mut(runner);
+        entity.insert(timestep::Interpolated::default());
+        entity.get_mut::<Transform>().unwrap().translation = translation;
+    }
+
+    // an enemy already mid dive right on top of the runner, it latches on the next step
+    fn spawn_diving_enemy(app: &mut App) -> Entity {
+        let runner = runner(app);
+        let position = app.world.get::<Transform>(runner).unwrap().translation;
+        let line_of_sight = app.world.spawn().id();
+        let mut enemy = {
+            let mut game_rng = app.world.resource_mut::<rng::GameRng>();
+            enemy::Enemy::new(line_of_sight, position, &enemy_archetypes::EnemyArchetype::default(),
+                              game_rng.stream(rng::RngStream::Enemy))
+        };
+        enemy.state = enemy::EnemyState::Dive;
+        enemy.target = Some(runner);
+
+        app.world.spawn()
+                 .insert_bundle(SpatialBundle::from_transform(Transform::from_translation(position + Vec3::new(0.2, 0.0, 0.0))))
+                 .insert(enemy)
+                 .insert(enemy::EnemyPath::default())
+                 .insert(AnimationLink {
+                     entity: None
+                 })
+                 .id()
+    }
+
+    #[test]
+    fn carrying_the_football_over_the_goal_line_scores() {
+        let mut app = empty_round();
+        move_runner(&mut app, Vec3::new(0.0, 0.0, RIGHT_GOAL - 3.0));
+        let runner = runner(&mut app);
+        app.world.get_mut::<player::Player>(runner).unwrap().has_football = true;
+
+        // pushed towards the right goal
+        app.world.resource_mut::<game_controller::GameController>().stick = Vec2::new(1.0, 0.0);
+        simulate(&mut app, 1.0);
+
+        let game_state = app.world.resource::<game_state::GameState>();
+        assert_eq!(game_state.score, 100);
+        assert_eq!(game_state.player_scores, [100, 0]);
+        assert!(game_state.touchdown_on_leftside);
+        assert!(!runner_state(&mut app).has_football);
+    }
+
+    #[test]
+    fn standing_still_scores_nothing() {
+        let mut app = empty_round();
+        simulate(&mut app, 2.0);
+
+        let game_state = app.world.resource::<game_state::GameState>();
+        assert_eq!(game_state.score, 0);
+        assert_eq!(game_state.death_count, 0);
+    }
+
+    #[test]
+    fn a_blade_hit_counts_a_death_once_the_runner_is_down() {
+        let mut app = empty_round();
+        let runner = runner(&mut app);
+        app.world.resource_mut::<Events<player::PlayerBladeEvent>>().send(player::PlayerBladeEvent { entity: runner });
+
+        simulate(&mut app, 0.5);
+        assert!(runner_state(&mut app).is_dead);
+        assert_eq!(app.world.resource::<game_state::GameState>().death_count, 0);
+
+        simulate(&mut app, 1.5);
+        assert_eq!(app.world.resource::<game_state::GameState>().death_count, 1);
+        assert_eq!(app.world.resource::<cutscene::CutsceneState>().current, Some(cutscene::Cutscene::death(1)));
+    }
+
+    #[test]
+    fn attached_enemies_are_counted_on_the_runner_and_knocked_off_by_the_blade() {
+        let mut app = empty_round();
+        let enemy = spawn_diving_enemy(&mut app);
+        simulate(&mut app, 0.1);
+
+        assert_eq!(app.world.get::<enemy::Enemy>(enemy).unwrap().state, enemy::EnemyState::Attached);
+        assert_eq!(runner_state(&mut app).attached_enemies, 1);
+        assert_eq!(runner_state(&mut app).attached_strength, 1.0);
+
+        app.world.resource_mut::<Events<enemy::EnemyBladeEvent>>().send(enemy::EnemyBladeEvent { entity: enemy });
+        simulate(&mut app, 0.1);
+
+        assert_eq!(app.world.get::<enemy::Enemy>(enemy).unwrap().state, enemy::EnemyState::Launched);
+        assert_eq!(runner_state(&mut app).attached_enemies, 0);
+        assert_eq!(runner_state(&mut app).attached_strength, 0.0);
+    }
+
+    #[test]
+    fn three_attached_enemies_tackle_the_runner() {
+        let mut app = empty_round();
+        for _ in 0..3 {
+            spawn_diving_enemy(&mut app);
+        }
+        simulate(&mut app, 0.1);
+
+        assert_eq!(runner_state(&mut app).attached_enemies, 3);
+        assert!(runner_state(&mut app).is_tackled);
+
+        simulate(&mut app, 1.5);
+        assert_eq!(app.world.resource::<cutscene::CutsceneState>().current, Some(cutscene::Cutscene::new("tackle")));
+        // a tackle isn't a death
+        assert_eq!(app.world.resource::<game_state::GameState>().death_count, 0);
+    }
+}
@@ -0,0 +1,441 @@
+use crate::{
+    asset_loading, assets::GameAssets, audio::GameAudio, cleanup, game_state, menus,
+    options, persistence, title_screen::MenuAction, ui::text_size, AppState,
+    localization::{Localization, LocalizedText},
+};
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// The best runs on this machine, kept in high_scores.ron. When the last round is over the run
+// comes here: if it makes the table the player puts in their initials first, otherwise the table
+// is just shown. The title screen has its own way in for looking at it.
+
+const HIGH_SCORES_FILE: &str = "high_scores.ron";
+const TABLE_SIZE: usize = 10;
+const INITIALS: usize = 3;
+
+pub struct HighScoresPlugin;
+impl Plugin for HighScoresPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(HighScores::load())
+            .init_resource::<HighScoresScreen>()
+            .add_system_set(
+                SystemSet::on_enter(AppState::HighScores)
+                    .with_system(setup),
+            )
+            .add_system_set(
+                SystemSet::on_update(AppState::HighScores)
+                    .with_system(handle_input)
+                    .with_system(display_high_scores.after(handle_input)),
+            )
+            .add_system_set(
+                SystemSet::on_exit(AppState::HighScores)
+                    .with_system(cleanup::<CleanupMarker>),
+            );
+    }
+}
+
+#[derive(Component)]
+struct CleanupMarker;
+
+#[derive(Component)]
+struct HighScoreRow(usize);
+
+#[derive(Component)]
+struct PromptMarker;
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct HighScores {
+    pub entries: Vec::<HighScore>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HighScore {
+    pub initials: String,
+    pub total: usize,
+    // points from each round in the order they were played
+    pub rounds: Vec::<usize>,
+    pub deaths: usize,
+    // YYYY-MM-DD, empty where there was no clock to ask
+    pub date: String,
+    pub graphics_high: bool,
+    pub shadows_on: bool,
+    pub is_latest: bool,
+}
+
+impl HighScore {
+    fn from_run(game_state: &game_state::GameState) -> Self {
+        HighScore {
+            initials: String::new(),
+            total: game_state.round_scores.iter().sum(),
+            rounds: game_state.round_scores.clone(),
+            deaths: game_state.death_count,
+            date: today(),
+            graphics_high: game_state.graphics_high,
+            shadows_on: game_state.shadows_on,
+            is_latest: game_state.is_latest,
+        }
+    }
+}
+
+impl HighScores {
+    fn load() -> Self {
+        let mut high_scores = persistence::load::<HighScores>(HIGH_SCORES_FILE).unwrap_or_default();
+
+        // hand edited files can be in any order
+        high_scores.entries.sort_by(|a, b| b.total.cmp(&a.total));
+        high_scores.entries.truncate(TABLE_SIZE);
+        high_scores
+    }
+
+    // where a run with this total would go, None if it doesn't make the table
+    pub fn place_for(&self, total: usize) -> Option<usize> {
+        if total == 0 { return None; }
+
+        // a tie goes below the run that got there first
+        let place = self.entries.iter()
+                                .position(|entry| entry.total < total)
+                                .unwrap_or(self.entries.len());
+        if place < TABLE_SIZE { Some(place) } else { None }
+    }
+
+    fn add(&mut self, entry: HighScore) -> Option<usize> {
+        let place = self.place_for(entry.total)?;
+        self.entries.insert(place, entry);
+        self.entries.truncate(TABLE_SIZE);
+        persistence::save(HIGH_SCORES_FILE, self);
+
+        Some(place)
+    }
+}
+
+#[derive(Default)]
+struct HighScoresScreen {
+    // the run that just finished while its initials are being put in
+    pending: Option<HighScore>,
+    initials: [u8; INITIALS],
+    cursor: usize,
+    // the run that was just added
+    highlighted: Option<usize>,
+    // each runner's total when a versus run just finished
+    result: Option<[usize; 2]>,
+    // so the press that finished the last cutscene doesn't leave straight away
+    cooldown: f32,
+}
+
+fn setup(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    game_state: Res<game_state::GameState>,
+    high_scores: Res<HighScores>,
+    mut screen: ResMut<HighScoresScreen>,
+    text_scaler: text_size::TextScaler,
+    localization: Res<Localization>,
+) {
+    *screen = HighScoresScreen {
+        initials: [b'A'; INITIALS],
+        cooldown: 0.5,
+        ..Default::default()
+    };
+
+    // coming from the title screen there's no run to look at, and a versus run is two runners'
+    // points together so it doesn't go on the table
+    if game_state.versus && !game_state.round_scores.is_empty() {
+        screen.result = Some(game_state.player_totals);
+    } else if !game_state.round_scores.is_empty() {
+        let run = HighScore::from_run(&game_state);
+        if high_scores.place_for(run.total).is_some() {
+            screen.pending = Some(run);
+        }
+    }
+
+    commands
+        .spawn_bundle(Camera3dBundle {
+            ..Default::default()
+        })
+        .insert(CleanupMarker);
+
+    commands
+        .spawn_bundle(InputManagerBundle {
+            input_map: MenuAction::default_input_map(),
+            action_state: ActionState::default(),
+        })
+        .insert(CleanupMarker);
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(98.0)),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::ColumnReverse,
+                ..Default::default()
+            },
+            color: Color::NONE.into(),
+            ..Default::default()
+        })
+        .insert(CleanupMarker)
+        .with_children(|parent| {
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Percent(100.0), Val::Percent(18.0)),
+                        position_type: PositionType::Relative,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::FlexEnd,
+                        ..Default::default()
+                    },
+                    color: Color::NONE.into(),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    options::add_title(
+                        parent,
+                        localization.font(&game_assets),
+                        text_scaler.scale(menus::DEFAULT_FONT_SIZE * 1.2),
+                        &localization.get("high_scores.title"),
+                        vec![LocalizedText("high_scores.title".to_string())],
+                    );
+                });
+
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Percent(100.0), Val::Percent(12.0)),
+                        position_type: PositionType::Relative,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    color: Color::NONE.into(),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    add_text(parent, &localization, &game_assets, text_scaler.scale(menus::BY_LINE_FONT_SIZE), PromptMarker);
+                });
+
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Percent(90.0), Val::Percent(65.0)),
+                        position_type: PositionType::Relative,
+                        justify_content: JustifyContent::FlexStart,
+                        align_items: AlignItems::FlexStart,
+                        flex_direction: FlexDirection::ColumnReverse,
+                        ..Default::default()
+                    },
+                    color: Color::NONE.into(),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    for place in 0..TABLE_SIZE {
+                        add_text(parent, &localization, &game_assets, text_scaler.scale(menus::FOLLOW_FONT_SIZE), HighScoreRow(place));
+                    }
+                });
+        });
+}
+
+fn add_text(
+    builder: &mut ChildBuilder<'_, '_, '_>,
+    localization: &Localization,
+    game_assets: &GameAssets,
+    font_size: f32,
+    component: impl Component,
+) {
+    builder
+        .spawn_bundle(TextBundle {
+            style: Style {
+                position_type: PositionType::Relative,
+                margin: UiRect {
+                    top: Val::Auto,
+                    bottom: Val::Auto,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            text: Text::from_section(
+                "".to_string(),
+                TextStyle {
+                    font: localization.font(game_assets),
+                    font_size,
+                    color: Color::WHITE,
+                },
+            ),
+            ..Default::default()
+        })
+        .insert(component);
+}
+
+fn handle_input(
+    mut screen: ResMut<HighScoresScreen>,
+    mut high_scores: ResMut<HighScores>,
+    action_state: Query<&ActionState<MenuAction>>,
+    mut game_state: ResMut<game_state::GameState>,
+    mut assets_handler: asset_loading::AssetsHandler,
+    mut game_assets: ResMut<GameAssets>,
+    mut audio: GameAudio,
+    time: Res<Time>,
+) {
+    screen.cooldown -= time.delta_seconds();
+    if screen.cooldown > 0.0 { return; }
+
+    let action_state = match action_state.get_single() {
+        Ok(action_state) => action_state,
+        Err(_) => return,
+    };
+
+    if screen.pending.is_some() {
+        let cursor = screen.cursor;
+        if action_state.just_pressed(MenuAction::Up) {
+            audio.play_sfx(&game_assets.blip);
+            screen.initials[cursor] = cycle_letter(screen.initials[cursor], true);
+        }
+        if action_state.just_pressed(MenuAction::Down) {
+            audio.play_sfx(&game_assets.blip);
+            screen.initials[cursor] = cycle_letter(screen.initials[cursor], false);
+        }
+        if action_state.just_pressed(MenuAction::Left) {
+            audio.play_sfx(&game_assets.blip);
+            screen.cursor = cursor.saturating_sub(1);
+        }
+        if action_state.just_pressed(MenuAction::Right) {
+            audio.play_sfx(&game_assets.blip);
+            screen.cursor = (cursor + 1).min(INITIALS - 1);
+        }
+        if action_state.just_pressed(MenuAction::Select) {
+            audio.play_sfx(&game_assets.blip);
+            if cursor < INITIALS - 1 {
+                screen.cursor += 1;
+            } else if let Some(mut entry) = screen.pending.take() {
+                entry.initials = String::from_utf8_lossy(&screen.initials).to_string();
+                screen.highlighted = high_scores.add(entry);
+            }
+        }
+
+        return;
+    }
+
+    if action_state.just_pressed(MenuAction::Select) {
+        audio.play_sfx(&game_assets.blip);
+        *game_state = game_state::GameState::default();
+        assets_handler.load(AppState::TitleScreen, &mut game_assets, &game_state);
+    }
+}
+
+fn cycle_letter(letter: u8, forward: bool) -> u8 {
+    let index = letter.saturating_sub(b'A') % 26;
+    let index = if forward { (index + 1) % 26 } else { (index + 25) % 26 };
+    b'A' + index
+}
+
+fn display_high_scores(
+    screen: Res<HighScoresScreen>,
+    high_scores: Res<HighScores>,
+    localization: Res<Localization>,
+    game_assets: Res<GameAssets>,
+    mut prompt: Query<&mut Text, (With<PromptMarker>, Without<HighScoreRow>)>,
+    mut rows: Query<(&mut Text, &HighScoreRow), Without<PromptMarker>>,
+) {
+    let font = localization.font(&game_assets);
+
+    for mut text in &mut prompt {
+        let value = match screen.pending {
+            Some(_) => {
+                let initials = screen.initials.iter()
+                    .enumerate()
+                    .map(|(i, letter)| if i == screen.cursor { format!("[{}]", *letter as char) }
+                                       else { format!(" {} ", *letter as char) })
+                    .collect::<String>();
+                format!("{} {}", localization.get("high_scores.new"), initials)
+            },
+            None => match screen.result {
+                Some([one, two]) => {
+                    let result = if one == two {
+                        localization.get("versus.draw")
+                    } else {
+                        localization.get("versus.wins")
+                                    .replace("{number}", if one > two { "1" } else { "2" })
+                    };
+                    format!("{} {}", result.replace("{one}", &one.max(two).to_string())
+                                           .replace("{two}", &one.min(two).to_string()),
+                                     localization.get("high_scores.back"))
+                },
+                None => localization.get("high_scores.back"),
+            },
+        };
+        set_text(&mut text, value, &font, Color::WHITE);
+    }
+
+    for (mut text, row) in &mut rows {
+        let value = match high_scores.entries.get(row.0) {
+            Some(entry) => describe(row.0, entry, &localization),
+            None => format!("{:>2}. ---", row.0 + 1),
+        };
+        let color = if screen.highlighted == Some(row.0) { Color::YELLOW } else { Color::WHITE };
+        set_text(&mut text, value, &font, color);
+    }
+}
+
+// only touched when something's different so the text isn't laid out again every frame
+fn set_text(text: &mut Mut<Text>, value: String, font: &Handle<Font>, color: Color) {
+    let section = &text.sections[0];
+    if section.value != value || section.style.font != *font || section.style.color != color {
+        let section = &mut text.sections[0];
+        section.value = value;
+        section.style.font = font.clone();
+        section.style.color = color;
+    }
+}
+
+fn describe(place: usize, entry: &HighScore, localization: &Localization) -> String {
+    let rounds = entry.rounds.iter().map(|score| score.to_string()).collect::<Vec::<_>>().join(" ");
+    let settings = localization.get("high_scores.settings")
+        .replace("{graphics}", &localization.get(if entry.graphics_high { "options.high" } else { "options.low" }))
+        .replace("{shadows}", &localization.get(if entry.shadows_on { "options.on" } else { "options.off" }))
+        .replace("{version}", &localization.get(if entry.is_latest { "options.latest" } else { "options.jam" }));
+    let date = if entry.date.is_empty() { "----------".to_string() } else { entry.date.clone() };
+
+    localization.get("high_scores.row")
+        .replace("{place}", &format!("{:>2}", place + 1))
+        .replace("{initials}", &format!("{:<3}", entry.initials))
+        .replace("{total}", &format!("{:>6}", entry.total))
+        .replace("{rounds}", &rounds)
+        .replace("{deaths}", &entry.deaths.to_string())
+        .replace("{date}", &date)
+        .replace("{settings}", &settings)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn today() -> String {
+    let seconds = std::time::SystemTime::now()
+                      .duration_since(std::time::UNIX_EPOCH)
+                      .map(|since| since.as_secs())
+                      .unwrap_or(0);
+    let (year, month, day) = civil_from_days((seconds / 86_400) as i64);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// there's no system clock to ask in the browser
+#[cfg(target_arch = "wasm32")]
+fn today() -> String {
+    String::new()
+}
+
+// days since 1970-01-01 to (year, month, day), from Howard Hinnant's date algorithms
+#[cfg(not(target_arch = "wasm32"))]
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
@@ -1,13 +1,11 @@
 use crate::{
     asset_loading, assets::GameAssets, cleanup, collision, component_adder, game_camera,
     game_state, player, AppState, audio::GameAudio, component_adder::AnimationLink, maze,
-    combine, enemy, football, TOP_END, RIGHT_GOAL, LEFT_GOAL, BOTTOM_END, LEFT_END, RIGHT_END, banter, cutscene
+    combine, enemy, football, RIGHT_GOAL, LEFT_GOAL, BOTTOM_END, LEFT_END, RIGHT_END, banter, cutscene, rng, timestep, maze_generator, rounds::{self, LightingPreset},
 };
 use std::f32::consts::{TAU, PI};
 use bevy::gltf::Gltf;
 use bevy::prelude::*;
-use rand::thread_rng;
-use rand::prelude::SliceRandom;
 use bevy::render::render_resource::{
     Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
 };
@@ -45,13 +43,17 @@ impl Plugin for InGamePlugin {
         );
     }
 }
+// in versus the light sits between the runners
 fn spotlight_follow_player(
-    player: Query<&Transform, (With<player::Player>, Without<SpotLight>)>,
+    players: Query<&Transform, (With<player::Player>, Without<SpotLight>)>,
     mut query: Query<(&mut Transform, &mut SpotLight)>
 ) {
+    let runners = players.iter().count();
+    if runners == 0 { return; }
+    let target = players.iter().map(|player| player.translation).sum::<Vec3>() / runners as f32;
+
     for (mut transform, mut angles) in query.iter_mut() {
-        let player = player.single();
-        *transform = transform.looking_at(player.translation, Vec3::Y);
+        *transform = transform.looking_at(target, Vec3::Y);
     }
 }
 
@@ -85,6 +87,10 @@ pub fn load(
     assets_handler.add_animation(&mut game_assets.person_run,"models/person.glb#Animation2");
     assets_handler.add_animation(&mut game_assets.person_dive,"models/person.glb#Animation0");
     assets_handler.add_glb(&mut game_assets.enemy, "models/enemy.glb");
+    assets_handler.add_enemy_archetypes(&mut game_assets.enemy_archetypes, "data/enemies.archetypes.ron");
+    assets_handler.add_cutscene_scripts(&mut game_assets.cutscenes, "data/game.cutscenes.ron");
+    assets_handler.add_commentary(&mut game_assets.commentary, "data/game.commentary.ron");
+    assets_handler.add_banter(&mut game_assets.banter, "data/game.banter.ron");
     assets_handler.add_glb(&mut game_assets.combine, "models/combine.glb");
     assets_handler.add_animation(&mut game_assets.combine_drive,"models/combine.glb#Animation0");
 
@@ -102,11 +108,8 @@ pub fn load(
     assets_handler.add_audio(&mut game_assets.bill_speak, "audio/bill_speak.wav");
     assets_handler.add_audio(&mut game_assets.bgm, "audio/combine.ogg");
 
-    match game_state.current_round {
-        1 => assets_handler.add_glb(&mut game_assets.maze, "models/maze_01.glb"),
-        2 => assets_handler.add_glb(&mut game_assets.maze, "models/maze_01.glb"),
-        _ => assets_handler.add_glb(&mut game_assets.maze, "models/maze.glb"),
-    }
+    let maze_model = assets_handler.rounds.get(game_state.current_round).maze_model.clone();
+    assets_handler.add_glb(&mut game_assets.maze, &maze_model);
 
     assets_handler.add_material(&mut game_assets.bill_icon, "textures/bill.png", true);
     assets_handler.add_material(&mut game_assets.will_icon, "textures/will.png", true);
@@ -182,7 +185,6 @@ pub fn load(
 pub fn setup(
     mut commands: Commands,
     game_assets: Res<GameAssets>,
-    asset_server: Res<AssetServer>,
     assets_gltf: Res<Assets<Gltf>>,
     mut game_state: ResMut<game_state::GameState>,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -192,17 +194,163 @@ pub fn setup(
     mut banter_state: ResMut<banter::BanterState>,
     cutscene_state: Res<cutscene::CutsceneState>,
     mut football_launch_event_writer: EventWriter<football::LaunchFootballEvent>,
+    mut game_rng: ResMut<rng::GameRng>,
+    maze_settings: Res<maze_generator::MazeSettings>,
+    rounds: Res<rounds::Rounds>,
     mut camera: Query<&mut Transform, With<game_camera::PanOrbitCamera>>,
     corn_stalks: Query<(&maze::CornStalk, &Transform), Without<game_camera::PanOrbitCamera>>,
 ) {
     println!("Setting up ingame!");
     game_state.title_screen_cooldown = 1.0;
-    game_state.attached_enemies = 0;
     game_state.enemies_spawned = false;
     game_state.touchdown_on_leftside = false;
 
-    match game_state.current_round {
-        1 => {
+    let round = rounds.get(game_state.current_round);
+    spawn_lighting(&mut commands, round.lighting, game_state.shadows_on);
+
+    let person_gltf = if game_state.death_count > 0 {
+        assets_gltf.get(&game_assets.person_blood.clone())
+    } else {
+        assets_gltf.get(&game_assets.person.clone())
+    };
+    if let Some(gltf) = person_gltf {
+        let runners = if game_state.versus { 2 } else { 1 };
+        for number in 0..runners {
+            commands.spawn_bundle(SceneBundle {
+                        scene: gltf.scenes[0].clone(),
+                        transform: {
+                            let mut t = Transform::from_translation(player::starting_position(number, &game_state));
+                            t.rotate_y(TAU * 0.75);
+                            t
+                        },
+                        ..default()
+                    })
+                    .insert_bundle(player::PlayerBundle::new(number, game_rng.stream(rng::RngStream::Player)))
+                    .insert(AnimationLink {
+                        entity: None
+                    })
+                    .with_children(|parent| {
+                        if let Some(football_gltf) = assets_gltf.get(&game_assets.football.clone()) {
+                            parent.spawn_bundle(SceneBundle {
+                                      scene: football_gltf.scenes[0].clone(),
+                                      transform: {
+                                          let mut t = Transform::from_scale(Vec3::splat(2.5));
+                                          t.translation.y += 1.0;
+                                          t.translation.x += 0.5;
+                                          t.rotation = Quat::from_rotation_z(TAU * 0.75);
+
+                                          t
+                                      },
+                                      visibility: Visibility { is_visible: false },
+                                      ..default()
+                                  })
+                                  .insert(football::CarriedFootball);
+                        }
+                    })
+                    .insert(CleanupMarker);
+        }
+    }
+
+    if let Some(gltf) = assets_gltf.get(&game_assets.enemy.clone()) {
+        // kickers
+        commands.spawn_bundle(SceneBundle {
+                    scene: gltf.scenes[0].clone(),
+                    transform: {
+                        let mut t = Transform::from_xyz(6.976, 0.0, -48.0);
+                        t.rotation = Quat::from_rotation_y(TAU * 0.75);
+                        t
+                    },
+                    ..default()
+                })
+                .insert(CleanupMarker);
+        commands.spawn_bundle(SceneBundle {
+                    scene: gltf.scenes[0].clone(),
+                    transform: {
+                        let mut t = Transform::from_xyz(6.976, 0.0, 48.0);
+                        t.rotation = Quat::from_rotation_y(TAU * 0.25);
+                        t
+                    },
+                    ..default()
+                })
+                .insert(CleanupMarker);
+    }
+
+    if let Some(gltf) = assets_gltf.get(&game_assets.combine.clone()) {
+        let unharvested_corn = if game_state.corn_spawned {
+                                   corn_stalks.iter()
+                                              .filter(|(c, _)| !c.is_harvested)
+                                              .map(|(_, t)| t.translation)
+                                              .collect::<Vec::<_>>()
+                               } else {
+                                   vec![]
+                               };
+        let lanes = combine::starting_lanes(round.combines.len(), &unharvested_corn, game_rng.stream(rng::RngStream::Combine));
+
+        for (definition, lane) in round.combines.iter().zip(lanes) {
+            commands.spawn_bundle(SceneBundle {
+                        scene: gltf.scenes[0].clone(),
+                        transform: {
+                            let mut t = Transform::from_xyz(lane, 0.0, (game_state.maze_size / 2.0));
+                            t.rotate_y(TAU * 0.25);
+                            t
+                        },
+
+                        ..default()
+                    })
+                    .insert(combine::Combine::create(game_state.is_latest, definition, lane))
+                    .insert(timestep::Interpolated::default())
+                    .insert(AnimationLink {
+                        entity: None
+                    })
+                    .insert(CleanupMarker);
+        }
+    }
+
+    if let Some(gltf) = assets_gltf.get(&game_assets.maze.clone()) {
+        commands.spawn_bundle(SceneBundle {
+                    scene: gltf.scenes[0].clone(),
+                    ..default()
+                })
+                .insert(CleanupMarker);
+    }
+
+    if round.generate_corn && !game_state.corn_spawned {
+        commands.spawn_bundle(SpatialBundle::default())
+                .insert(maze::generated_marker(maze::field_area(), &game_state, &maze_settings, &mut game_rng))
+                .insert(CleanupMarker);
+    }
+
+    component_adder.reset();
+
+    if cutscene_state.current.is_none() {
+        if camera.iter().len() == 0 {
+            game_camera::spawn_camera(&mut commands, CleanupMarker, &game_assets,
+                                      Vec3::new(game_camera::INGAME_CAMERA_X, 
+                                               game_camera::INGAME_CAMERA_Y, 
+                                               LEFT_GOAL),
+                                  Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
+                                            game_camera::INGAME_CAMERA_ROTATION_ANGLE));
+        } else {
+            for mut camera in &mut camera {
+                camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
+                                               game_camera::INGAME_CAMERA_Y, 
+                                               LEFT_GOAL);
+                camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
+                                            game_camera::INGAME_CAMERA_ROTATION_ANGLE);
+            }
+        }
+
+        football_launch_event_writer.send(football::LaunchFootballEvent);
+    } else if camera.iter().len() == 0 {
+        game_camera::spawn_camera(&mut commands, CleanupMarker, &game_assets,
+                                  Vec3::new(22.5, 1.5, 0.0),
+                            Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247));
+    }
+}
+
+fn spawn_lighting(commands: &mut Commands, lighting: LightingPreset, shadows: bool) {
+    match lighting {
+        LightingPreset::Sunset => {
             // lights
             commands.insert_resource(AmbientLight {
                 color: Color::WHITE,
@@ -224,7 +372,7 @@ pub fn setup(
                         far: 10.0 * HALF_SIZE,
                         ..Default::default()
                     },
-                    shadows_enabled: game_state.shadows_on,
+                    shadows_enabled: shadows,
                     ..Default::default()
                 },
                 transform: Transform {
@@ -235,7 +383,7 @@ pub fn setup(
             })
             .insert(CleanupMarker);
         },
-        2 => {
+        LightingPreset::Night => {
             // lights
             commands.insert_resource(AmbientLight {
                 color: Color::ALICE_BLUE,
@@ -249,7 +397,7 @@ pub fn setup(
                         intensity: 10000.0, // lumens
                         color: Color::WHITE,
                         range: 77.0,
-                        shadows_enabled: game_state.shadows_on,
+                        shadows_enabled: shadows,
                         shadow_depth_bias: 10.0,
                         inner_angle: 0.1,
                         outer_angle: 0.2,
@@ -266,7 +414,7 @@ pub fn setup(
                         intensity: 10000.0, // lumens
                         color: Color::WHITE,
                         range: 77.0,
-                        shadows_enabled: game_state.shadows_on,
+                        shadows_enabled: shadows,
                         shadow_depth_bias: 10.0,
                         inner_angle: 0.1,
                         outer_angle: 0.2,
@@ -276,7 +424,7 @@ pub fn setup(
                 })
                 .insert(CleanupMarker);
         },
-        _ => {
+        LightingPreset::Day => {
             // lights
             commands.insert_resource(AmbientLight {
                 color: Color::WHITE,
@@ -297,7 +445,7 @@ pub fn setup(
                         far: 10.0 * HALF_SIZE,
                         ..Default::default()
                     },
-                    shadows_enabled: game_state.shadows_on,
+                    shadows_enabled: shadows,
                     ..Default::default()
                 },
                 transform: Transform {
@@ -309,138 +457,4 @@ pub fn setup(
             .insert(CleanupMarker);
         }
     }
-
-    let person_gltf = if game_state.death_count > 0 {
-        assets_gltf.get(&game_assets.person_blood.clone())
-    } else {
-        assets_gltf.get(&game_assets.person.clone())
-    };
-    if let Some(gltf) = person_gltf {
-        commands.spawn_bundle(SceneBundle {
-                    scene: gltf.scenes[0].clone(),
-                    transform: {
-                        let mut t = Transform::from_xyz(0.0, 0.0, LEFT_GOAL);
-                        t.rotate_y(TAU * 0.75);
-                        t
-                    },
-                    ..default()
-                })
-                .insert_bundle(player::PlayerBundle::new())
-                .insert(AnimationLink {
-                    entity: None
-                })
-                .with_children(|parent| {
-                    if let Some(football_gltf) = assets_gltf.get(&game_assets.football.clone()) {
-                        parent.spawn_bundle(SceneBundle {
-                                  scene: football_gltf.scenes[0].clone(),
-                                  transform: {
-                                      let mut t = Transform::from_scale(Vec3::splat(2.5));
-                                      t.translation.y += 1.0;
-                                      t.translation.x += 0.5;
-                                      t.rotation = Quat::from_rotation_z(TAU * 0.75);
-
-                                      t
-                                  },
-                                  visibility: Visibility { is_visible: false },
-                                  ..default()
-                              })
-                              .insert(football::CarriedFootball);
-                    }
-                })
-                .insert(CleanupMarker);
-    }
-
-    if let Some(gltf) = assets_gltf.get(&game_assets.enemy.clone()) {
-        // kickers
-        commands.spawn_bundle(SceneBundle {
-                    scene: gltf.scenes[0].clone(),
-                    transform: {
-                        let mut t = Transform::from_xyz(6.976, 0.0, -48.0);
-                        t.rotation = Quat::from_rotation_y(TAU * 0.75);
-                        t
-                    },
-                    ..default()
-                })
-                .insert(CleanupMarker);
-        commands.spawn_bundle(SceneBundle {
-                    scene: gltf.scenes[0].clone(),
-                    transform: {
-                        let mut t = Transform::from_xyz(6.976, 0.0, 48.0);
-                        t.rotation = Quat::from_rotation_y(TAU * 0.25);
-                        t
-                    },
-                    ..default()
-                })
-                .insert(CleanupMarker);
-    }
-
-    if let Some(gltf) = assets_gltf.get(&game_assets.combine.clone()) {
-        let combine_position =
-            if game_state.corn_spawned && corn_stalks.iter().len() > 0 {
-                let unharvested_corn = corn_stalks.iter()
-                                                  .filter(|(c, _)| !c.is_harvested)
-                                                  .collect::<Vec::<_>>();
-                let mut rng = thread_rng();
-                let corn_transform = unharvested_corn.choose(&mut rng).map(|(_, t)| *t);
-                let starting_row =
-                    if let Some(corn_transform) = corn_transform  {
-                        corn_transform.translation.x
-                    } else {
-                        TOP_END * 0.5
-                    };
-                Transform::from_xyz(starting_row, 0.0, (game_state.maze_size / 2.0))
-            } else {
-                Transform::from_xyz(TOP_END * 0.5, 0.0, (game_state.maze_size / 2.0))
-            };
-        commands.spawn_bundle(SceneBundle {
-                    scene: gltf.scenes[0].clone(),
-                    transform: {
-                        let mut t = combine_position;
-                        t.rotate_y(TAU * 0.25);
-                        t
-                    },
-
-                    ..default()
-                })
-                .insert(combine::Combine::create(game_state.is_latest))
-                .insert(AnimationLink {
-                    entity: None
-                })
-                .insert(CleanupMarker);
-    }
-
-    if let Some(gltf) = assets_gltf.get(&game_assets.maze.clone()) {
-        commands.spawn_bundle(SceneBundle {
-                    scene: gltf.scenes[0].clone(),
-                    ..default()
-                })
-                .insert(CleanupMarker);
-    }
-
-    component_adder.reset();
-
-    if cutscene_state.current.is_none() {
-        if camera.iter().len() == 0 {
-            game_camera::spawn_camera(&mut commands, CleanupMarker, &game_assets,
-                                      Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                               game_camera::INGAME_CAMERA_Y, 
-                                               LEFT_GOAL),
-                                  Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                            game_camera::INGAME_CAMERA_ROTATION_ANGLE));
-        } else {
-            for mut camera in &mut camera {
-                camera.translation = Vec3::new(game_camera::INGAME_CAMERA_X, 
-                                               game_camera::INGAME_CAMERA_Y, 
-                                               LEFT_GOAL);
-                camera.rotation = Quat::from_axis_angle(game_camera::INGAME_CAMERA_ROTATION_AXIS, 
-                                            game_camera::INGAME_CAMERA_ROTATION_ANGLE);
-            }
-        }
-
-        football_launch_event_writer.send(football::LaunchFootballEvent);
-    } else if camera.iter().len() == 0 {
-        game_camera::spawn_camera(&mut commands, CleanupMarker, &game_assets,
-                                  Vec3::new(22.5, 1.5, 0.0),
-                            Quat::from_axis_angle(Vec3::new(-0.034182332, -0.9987495, -0.03648749), 1.5735247));
-    }
 }
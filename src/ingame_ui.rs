@@ -1,9 +1,11 @@
 use crate::{
     assets::GameAssets, cleanup, game_state, menus, AppState, ui::text_size, ingame, other_persons,
-    component_adder::AnimationLink, game_camera, maze, audio::GameAudio,
+    component_adder::AnimationLink, game_camera, maze, audio::GameAudio, cutscene_script::HostAnimation,
+    localization::Localization,
 };
 use bevy::prelude::*;
 use bevy::ui::UiColor;
+use serde::Deserialize;
 use std::mem;
 use bevy::render::{
     view::RenderLayers,
@@ -24,7 +26,7 @@ impl Plugin for InGameUIPlugin {
                 SystemSet::on_update(AppState::InGame)
                     .with_system(update_ui)
                     .with_system(display_textbox)
-                    .with_system(handle_textbox_events)
+                    .with_system(handle_textbox_events.before(display_textbox))
                     //.with_system(detect_round_over),
             );
     }
@@ -37,10 +39,21 @@ fn update_ui(
     game_state: Res<game_state::GameState>,
     mut score_indicators: Query<&mut Text, (With<ScoreIndicator>, Without<CornIndicator>)>,
     mut corn_indicators: Query<&mut Text, (With<CornIndicator>, Without<ScoreIndicator>)>,
-    corn_stalks: Query<Entity, With<maze::CornStalk>>
+    corn_stalks: Query<Entity, With<maze::CornStalk>>,
+    localization: Res<Localization>,
 ) {
+    let score = if game_state.versus {
+        // what's been banked so far plus this round
+        let one = game_state.player_totals[0] + game_state.player_scores[0];
+        let two = game_state.player_totals[1] + game_state.player_scores[1];
+        localization.get("versus.score")
+                    .replace("{one}", &one.to_string())
+                    .replace("{two}", &two.to_string())
+    } else {
+        game_state.score.to_string()
+    };
     for mut text in score_indicators.iter_mut() {
-        text.sections[0].value = game_state.score.to_string();
+        text.sections[0].value = score.clone();
     }
     for mut text in corn_indicators.iter_mut() {
         text.sections[0].value = corn_stalks.iter().len().to_string();
@@ -52,6 +65,7 @@ fn setup(
     game_assets: Res<GameAssets>,
     mut game_state: ResMut<game_state::GameState>,
     text_scaler: text_size::TextScaler,
+    localization: Res<Localization>,
 ) {
     let scale = (text_scaler.window_size.width * 0.1) / ingame::RENDER_TEXTURE_SIZE as f32;
     commands
@@ -140,9 +154,9 @@ fn setup(
                         );
                         add_title(
                             parent,
-                            game_assets.font.clone(),
+                            localization.font(&game_assets),
                             text_scaler.scale(menus::DEFAULT_FONT_SIZE * 0.8),
-                            "Pts ",
+                            &format!("{} ", localization.get("ingame.points")),
                             Vec::<ingame::CleanupMarker>::new(), // just an empty vec since can't do <impl Trait>
                         );
                     });
@@ -168,9 +182,9 @@ fn setup(
                         );
                         add_title(
                             parent,
-                            game_assets.font.clone(),
+                            localization.font(&game_assets),
                             text_scaler.scale(menus::DEFAULT_FONT_SIZE * 0.8),
-                            "Corn",
+                            &localization.get("ingame.corn"),
                             Vec::<ingame::CleanupMarker>::new(), // just an empty vec since can't do <impl Trait>
                         );
                     });
@@ -220,6 +234,8 @@ pub struct TextBox {
     cooldown: f32,
     current_animation: Handle<AnimationClip>,
     after_text_displayed_cooldown: f32,
+    // what SetTextBoxEvent said this was worth, so commentary knows whether it can cut in
+    priority: u32,
 }
 
 impl Default for TextBox {
@@ -231,11 +247,20 @@ impl Default for TextBox {
             cooldown: 0.0,
             current_animation: Handle::<AnimationClip>::default(),
             after_text_displayed_cooldown: 0.0,
+            priority: 0,
         }
     }
 }
 
 impl TextBox {
+    pub fn is_showing(&self) -> bool {
+        self.queued_text.is_some() || self.after_text_displayed_cooldown > 0.0
+    }
+
+    pub fn priority(&self) -> u32 {
+        self.priority
+    }
+
     fn take_next_text(&mut self) -> Option::<TextBoxText> {
         if let Some(texts) = &mut self.texts {
             if texts.is_empty() {
@@ -261,12 +286,29 @@ pub struct TextBoxText {
     pub after_text_displayed_delay: f32,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Deserialize)]
 pub enum DisplayCharacter {
     Bill,
     Will
 }
 
+// a line the way banter and commentary write them in their data files, (who, animation, string key)
+#[derive(Clone, Debug, Deserialize)]
+pub struct HostLine(pub DisplayCharacter, pub HostAnimation, pub String);
+
+impl HostLine {
+    pub fn to_text(&self, speed: f32, game_assets: &GameAssets, localization: &Localization) -> TextBoxText {
+        let HostLine(character, animation, key) = self;
+        TextBoxText {
+            text: localization.get(key),
+            speed,
+            character: character.clone(),
+            animation_clip: animation.clip(game_assets),
+            after_text_displayed_delay: 1.0,
+        }
+    }
+}
+
 fn display_textbox(
     mut commands: Commands,
     game_assets: Res<GameAssets>,
@@ -280,6 +322,7 @@ fn display_textbox(
     mut host_camera: Query<(&mut Transform, &game_camera::HostCamera)>,
     mut host_sprite: Query<&mut Handle<Image>, With<HostSpriteMarker>>,
     text_scaler: text_size::TextScaler,
+    localization: Res<Localization>,
     time: Res<Time>,
 ) {
     let still_displaying_last_text = textbox.after_text_displayed_cooldown > 0.0;
@@ -392,7 +435,7 @@ fn display_textbox(
                             text: Text::from_section(
                                 text_to_display.trim(),
                                 TextStyle {
-                                    font: game_assets.font.clone(),
+                                    font: localization.font(&game_assets),
                                     font_size,
                                     color: Color::WHITE,
                                 }
@@ -419,15 +462,27 @@ fn display_textbox(
 
 pub struct SetTextBoxEvent {
     pub texts: Vec::<TextBoxText>,
+    // banter is 0, higher numbers are allowed to talk over lower ones
+    pub priority: u32,
 }
 
 fn handle_textbox_events(
+    mut commands: Commands,
     mut textbox_events: EventReader<SetTextBoxEvent>,
     mut textbox: ResMut<TextBox>,
+    text_container: Query<&Children, With<TextContainerMarker>>,
 ) {
     for event in textbox_events.iter() {
+        // cut off whatever was halfway through being said
+        for children in &text_container {
+            for entity in children.iter() {
+                commands.entity(*entity).despawn_recursive();
+            }
+        }
+
         *textbox = TextBox::default();
         textbox.texts = Some(event.texts.to_vec()); 
+        textbox.priority = event.priority;
         textbox.queued_text = textbox.take_next_text();
     }
 }
@@ -477,8 +532,3 @@ pub fn add_title(
         text_bundle.insert(c);
     });
 }
-
-
-
-pub struct Commentary {
-}
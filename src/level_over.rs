@@ -1,7 +1,7 @@
 use crate::{
     assets::GameAssets, cleanup, game_state, menus, AppState, ui::text_size, ingame, other_persons,
     component_adder::AnimationLink, game_camera, ingame_ui, title_screen::MenuAction, LEFT_GOAL, football,
-    asset_loading, cutscene, assets,
+    asset_loading, cutscene, assets, rounds,
 };
 use bevy::prelude::*;
 
@@ -22,17 +22,15 @@ fn load_next_level(
     mut game_state: ResMut<game_state::GameState>,
     mut game_assets: ResMut<assets::GameAssets>,
     mut cutscene_state: ResMut<cutscene::CutsceneState>,
+    rounds: Res<rounds::Rounds>,
 ) {
+    game_state.bank_round();
     game_state.score = 0;
+    game_state.player_scores = [0; 2];
     game_state.corn_spawned = false;
     game_state.current_round += 1;
 
-    println!("setting up round {}", game_state.current_round);
-    match game_state.current_round {
-        1 => cutscene_state.init(cutscene::Cutscene::LevelTwoIntro),
-        2 => cutscene_state.init(cutscene::Cutscene::LevelThreeIntro),
-        _ => cutscene_state.init(cutscene::Cutscene::Intro),
-    }
+    cutscene_state.init(rounds.get(game_state.current_round).intro.clone());
 
     assets_handler.load(AppState::InGame, &mut game_assets, &game_state);
 }
@@ -0,0 +1,208 @@
+use crate::assets::GameAssets;
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+// Everything the player reads goes through a string table keyed by id. The tables live in
+// assets/locale, one per language listed in game.languages.ron, and a key the current language
+// doesn't have falls back to English and then to the key itself so a gap is easy to spot.
+
+pub struct LocalizationPlugin;
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<Languages>()
+            .init_asset_loader::<LanguagesLoader>()
+            .init_resource::<Localization>()
+            .add_system_to_stage(CoreStage::PreUpdate, update_localization)
+            .add_system(update_localized_text);
+    }
+}
+
+const ENGLISH: &str = "en";
+
+#[derive(TypeUuid)]
+#[uuid = "8f3c2a61-5d7e-4b09-a1c4-6e2b9d0f7a53"]
+pub struct Languages {
+    pub languages: Vec::<Language>,
+}
+
+#[derive(Clone)]
+pub struct Language {
+    pub code: String,
+    // what the options menu shows, written in the language itself
+    pub name: String,
+    pub font: Option<Handle<Font>>,
+    pub strings: HashMap::<String, String>,
+}
+
+pub struct Localization {
+    locale: String,
+    languages: Vec::<Language>,
+    // only set once the font has actually loaded, until then text stays in monogram
+    font: Option<Handle<Font>>,
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Localization {
+            locale: ENGLISH.to_string(),
+            languages: vec![],
+            font: None,
+        }
+    }
+}
+
+impl Localization {
+    pub fn get(&self, key: &str) -> String {
+        self.language(&self.locale)
+            .and_then(|language| language.strings.get(key))
+            .or_else(|| self.language(ENGLISH).and_then(|language| language.strings.get(key)))
+            .cloned()
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    // a locale set before the tables have loaded is kept and used once they are
+    pub fn set_locale(&mut self, locale: &str) {
+        self.locale = locale.to_string();
+        self.font = None;
+    }
+
+    // the name of the current language for the options menu
+    pub fn language_name(&self) -> String {
+        self.language(&self.locale)
+            .map(|language| language.name.clone())
+            .unwrap_or_else(|| self.locale.clone())
+    }
+
+    pub fn cycle_locale(&mut self, forward: bool) {
+        if self.languages.is_empty() { return; }
+
+        let count = self.languages.len();
+        let current = self.languages.iter().position(|language| language.code == self.locale).unwrap_or(0);
+        let next = if forward { (current + 1) % count } else { (current + count - 1) % count };
+        let locale = self.languages[next].code.clone();
+        self.set_locale(&locale);
+    }
+
+    // the font for localized text, monogram unless the language brings its own
+    pub fn font(&self, game_assets: &GameAssets) -> Handle<Font> {
+        self.font.clone().unwrap_or_else(|| game_assets.font.clone())
+    }
+
+    fn language(&self, code: &str) -> Option<&Language> {
+        self.languages.iter().find(|language| language.code == code)
+    }
+}
+
+fn update_localization(
+    mut localization: ResMut<Localization>,
+    languages: Res<Assets<Languages>>,
+    fonts: Res<Assets<Font>>,
+    game_assets: Res<GameAssets>,
+) {
+    if languages.is_changed() {
+        if let Some(loaded) = languages.get(&game_assets.languages) {
+            localization.languages = loaded.languages.clone();
+            localization.font = None;
+        }
+    }
+
+    if localization.font.is_none() {
+        let font = localization.language(&localization.locale)
+                               .and_then(|language| language.font.clone())
+                               .filter(|font| fonts.contains(font));
+        if font.is_some() {
+            localization.font = font;
+        }
+    }
+}
+
+// text spawned with this is redone whenever the language (or its font) changes,
+// for anything on screen while the language can be switched
+#[derive(Component)]
+pub struct LocalizedText(pub String);
+
+fn update_localized_text(
+    localization: Res<Localization>,
+    game_assets: Res<GameAssets>,
+    mut texts: Query<(&LocalizedText, &mut Text)>,
+) {
+    if !localization.is_changed() { return; }
+
+    for (localized_text, mut text) in &mut texts {
+        text.sections[0].value = localization.get(&localized_text.0);
+        text.sections[0].style.font = localization.font(&game_assets);
+    }
+}
+
+#[derive(Deserialize)]
+struct LanguageList {
+    languages: Vec::<LanguageEntry>,
+}
+
+#[derive(Deserialize)]
+struct LanguageEntry {
+    code: String,
+    name: String,
+    #[serde(default)]
+    font: Option<String>,
+}
+
+#[derive(Default)]
+pub struct LanguagesLoader;
+
+impl AssetLoader for LanguagesLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let list: LanguageList = ron::de::from_bytes(bytes)?;
+            let directory = load_context.path().parent().unwrap_or_else(|| Path::new("")).to_path_buf();
+
+            let mut languages = vec![];
+            let mut fonts = vec![];
+            for entry in list.languages {
+                // a table that's missing or broken only costs that language its strings
+                let path = directory.join(format!("{}.strings.ron", entry.code));
+                let strings = match load_context.read_asset_bytes(&path).await {
+                    Ok(bytes) => ron::de::from_bytes(&bytes).unwrap_or_else(|error| {
+                        println!("couldn't read {}: {}", path.display(), error);
+                        HashMap::new()
+                    }),
+                    Err(error) => {
+                        println!("couldn't load {}: {}", path.display(), error);
+                        HashMap::new()
+                    }
+                };
+
+                let font = entry.font.map(|font| {
+                    fonts.push(font.clone().into());
+                    load_context.get_handle(font.as_str())
+                });
+
+                languages.push(Language {
+                    code: entry.code,
+                    name: entry.name,
+                    font,
+                    strings,
+                });
+            }
+
+            load_context.set_default_asset(LoadedAsset::new(Languages { languages }).with_dependencies(fonts));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["languages.ron"]
+    }
+}
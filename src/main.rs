@@ -12,27 +12,44 @@ mod assets;
 mod banter;
 mod audio;
 mod cutscene;
+mod cutscene_script;
 mod splash;
 mod billboard;
 mod collision;
 mod combine;
+mod commentary;
 mod component_adder;
+mod controls;
 mod direction;
 mod enemy;
+mod enemy_archetypes;
 mod football;
 mod level_over;
+mod localization;
 mod game_controller;
 mod game_camera;
 mod game_state;
+mod harvest_strategy;
+mod headless;
+mod high_scores;
 mod ingame;
 mod ingame_ui;
 mod maze;
+mod maze_generator;
 mod menus;
 mod player;
 mod options;
+mod replay;
+mod rng;
+mod rounds;
+mod settings;
 mod other_persons;
+mod pathing;
+mod pause;
+mod persistence;
 mod title_screen;
 mod shaders;
+mod timestep;
 mod ui;
 mod wasm;
 
@@ -44,6 +61,11 @@ const BOTTOM_END:f32 = -19.471;
 const TOP_END:f32 = 20.471;
 
 fn main() {
+    if std::env::args().any(|arg| arg == "--headless") {
+        headless::run();
+        return;
+    }
+
     App::new()
 //      .insert_resource(AssetServerSettings {
 //          watch_for_changes: true,
@@ -52,21 +74,32 @@ fn main() {
 //      .add_plugin(LogDiagnosticsPlugin::default())
 //      .add_plugin(FrameTimeDiagnosticsPlugin::default())
         .add_plugins(DefaultPlugins)
+        // gameplay plugins add systems to its stage so it has to come first
+        .add_plugin(timestep::TimestepPlugin)
 //      .add_plugin(WorldInspectorPlugin::new())
         .add_plugin(audio::GameAudioPlugin)
         .add_plugin(assets::AssetsPlugin)
         .add_plugin(banter::BanterPlugin)
+        .add_plugin(commentary::CommentaryPlugin)
         .add_plugin(cutscene::CutscenePlugin)
+        .add_plugin(cutscene_script::CutsceneScriptPlugin)
         .add_plugin(asset_loading::AssetLoadingPlugin)
         .add_plugin(billboard::BillboardPlugin)
+        .add_plugin(collision::CollisionPlugin)
+        .add_plugin(pathing::PathingPlugin)
         .add_plugin(splash::SplashPlugin)
         .add_plugin(component_adder::ComponentAdderPlugin)
+        .add_plugin(controls::ControlsPlugin)
         .add_plugin(enemy::EnemyPlugin)
+        .add_plugin(enemy_archetypes::EnemyArchetypesPlugin)
         .add_plugin(options::OptionsMenuPlugin)
+        .add_plugin(pause::PausePlugin)
         .add_plugin(level_over::LevelOverPlugin)
+        .add_plugin(localization::LocalizationPlugin)
         .add_plugin(football::FootballPlugin)
         .add_plugin(combine::CombinePlugin)
         .add_plugin(game_state::GameStatePlugin)
+        .add_plugin(high_scores::HighScoresPlugin)
         .add_plugin(ingame_ui::InGameUIPlugin)
         .add_plugin(ingame::InGamePlugin)
         .add_plugin(maze::MazePlugin)
@@ -75,6 +108,10 @@ fn main() {
         .add_plugin(shaders::ShadersPlugin)
         .add_plugin(title_screen::TitlePlugin)
         .add_plugin(player::PlayerPlugin)
+        .add_plugin(rng::RngPlugin)
+        .add_plugin(rounds::RoundsPlugin)
+        .add_plugin(settings::SettingsPlugin)
+        .add_plugin(replay::ReplayPlugin)
         .add_plugin(ui::text_size::TextSizePlugin)
         .add_plugin(wasm::WasmPlugin)
         .add_system(window_settings)
@@ -84,10 +121,10 @@ fn main() {
         .run();
 }
 
-fn window_settings(mut windows: ResMut<Windows>){
+fn window_settings(mut windows: ResMut<Windows>, settings: Res<settings::Settings>){
     for mut window in windows.iter_mut() {
         window.set_title(String::from("USAFL Scouting Combine XLV"));
-        window.set_mode(WindowMode::BorderlessFullscreen)
+        window.set_mode(if settings.fullscreen { WindowMode::BorderlessFullscreen } else { WindowMode::Windowed })
     }
 }
 
@@ -99,6 +136,8 @@ pub enum AppState {
     Debug,
     TitleScreen,
     Options,
+    Controls,
+    HighScores,
     InGame,
     Splash,
     LevelOver,
@@ -118,8 +157,13 @@ fn bootstrap(
     game_state: ResMut<game_state::GameState>,
     mut clear_color: ResMut<ClearColor>,
     mut audio: audio::GameAudio,
+    settings: Res<settings::Settings>,
+    mut option_state: ResMut<options::OptionState>,
+    mut localization: ResMut<localization::Localization>,
 ) {
-    audio.set_volume();
+    audio.set_volume(&settings);
+    *option_state = options::OptionState::from_settings(&settings);
+    localization.set_locale(&settings.locale);
     clear_color.0 = Color::hex("000000").unwrap(); 
 
     assets_handler.load(AppState::Splash, &mut game_assets, &game_state);
@@ -165,7 +209,7 @@ fn debug(
     }
 
     if keys.just_pressed(KeyCode::G) {
-        cutscene_state.cutscene_index = 1000;
+        cutscene_state.skip();
     }
 
     if keys.just_pressed(KeyCode::T) {
@@ -203,7 +247,8 @@ fn debug(
 
         println!("Sent texts");
         textbox_event_writer.send(ingame_ui::SetTextBoxEvent {
-            texts
+            texts,
+            priority: 0,
         });
     }
 }
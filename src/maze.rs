@@ -1,15 +1,46 @@
 use bevy::prelude::*;
 use crate::{
     AppState, collision, assets::GameAssets, ingame, component_adder::{AnimationLink, ComponentAdder},
-    game_state,
+    game_state, rng, maze_generator::{self, CornGrid, MazeSettings},
+    LEFT_GOAL, RIGHT_GOAL, BOTTOM_END, TOP_END,
 };
 use bevy::gltf::Gltf;
-use rand::{random, Rng};
+use rand::Rng;
 
 #[derive(Component)]
 pub struct MazeMarker {
     pub spawned: bool,
     pub aabb: collision::WorldAabb,
+    // corn is only placed where the grid has it, without one the whole area is filled
+    pub grid: Option::<CornGrid>,
+}
+
+// the part of the field between the goals that generated mazes cover
+pub fn field_area() -> collision::WorldAabb {
+    collision::WorldAabb {
+        min: Vec3::new(BOTTOM_END + 1.0, 0.0, LEFT_GOAL + 8.0),
+        max: Vec3::new(TOP_END - 1.0, 0.0, RIGHT_GOAL - 8.0),
+    }
+}
+
+pub fn corn_spacing(game_state: &game_state::GameState) -> f32 {
+    if game_state.graphics_high { 0.8 } else { 1.5 }
+}
+
+pub fn generated_marker(
+    area: collision::WorldAabb,
+    game_state: &game_state::GameState,
+    settings: &MazeSettings,
+    game_rng: &mut rng::GameRng,
+) -> MazeMarker {
+    let seed = game_rng.stream(rng::RngStream::Maze).gen::<u64>();
+    let grid = maze_generator::generate(&area, corn_spacing(game_state), settings, seed);
+
+    MazeMarker {
+        spawned: false,
+        aabb: area,
+        grid: Some(grid),
+    }
 }
 
 #[derive(Component)]
@@ -28,7 +59,8 @@ pub struct ShrinkCorn {
 pub struct MazePlugin;
 impl Plugin for MazePlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(animate_corn)
+        app.init_resource::<MazeSettings>()
+           .add_system(animate_corn)
            .add_system(spawn_corn)
            .add_system_set(
             SystemSet::on_update(AppState::InGame)
@@ -93,35 +125,35 @@ fn spawn_corn(
     game_assets: Res<GameAssets>,
     game_state: Res<game_state::GameState>,
     mut component_adder: ResMut<ComponentAdder>,
+    mut game_rng: ResMut<rng::GameRng>,
 ) {
-    let maze_thickness = if game_state.graphics_high { 0.8 } else { 1.5 };
+    let maze_thickness = corn_spacing(&game_state);
     let corn_height = 80.0;
     let corn_thickness = 0.8;
 
-    if let Some(gltf) = assets_gltf.get(&game_assets.corn_stalk.clone()) {
-        let mut rng = rand::thread_rng();
-        for (mut maze_plane, mut visibility) in &mut maze_planes {
-            if maze_plane.spawned { continue; }
-
-            let rows = ((maze_plane.aabb.max.x - maze_plane.aabb.min.x) / maze_thickness) as usize;
-            let columns = ((maze_plane.aabb.max.z - maze_plane.aabb.min.z) / maze_thickness) as usize;
-
-            for row in 0..rows {
-                for column in 0..columns {
-                    let x = maze_plane.aabb.min.x + ((row as f32 + 0.5) * maze_thickness);
-                    let z = maze_plane.aabb.min.z + ((column as f32 + 0.5) * maze_thickness);
-                    commands .spawn_bundle(SceneBundle {
-                        scene: gltf.scenes[0].clone(),
-                        transform: {
-                            let mut t = Transform::from_xyz(x, 0.0, z);
-//                          t.scale.y = corn_height;
-//                          t.scale.x = corn_thickness;
-//                          t.scale.z = corn_thickness;
-                            t
-                        },
-                        ..default()
-                    })
-                    .insert(collision::Collidable {
+    let corn_scene = assets_gltf.get(&game_assets.corn_stalk.clone()).map(|gltf| gltf.scenes[0].clone());
+    let rng = game_rng.stream(rng::RngStream::Corn);
+    for (mut maze_plane, mut visibility) in &mut maze_planes {
+        if maze_plane.spawned { continue; }
+
+        let grid = maze_plane.grid
+                             .take()
+                             .unwrap_or_else(|| CornGrid::new(&maze_plane.aabb, maze_thickness, true));
+
+        for row in 0..grid.rows {
+            for column in 0..grid.columns {
+                if !grid.has_corn(row, column) { continue; }
+
+                let center = grid.cell_center(row, column);
+                let (x, z) = (center.x, center.z);
+                let mut corn = commands.spawn_bundle(SpatialBundle::from_transform({
+                    let mut t = Transform::from_xyz(x, 0.0, z);
+//                  t.scale.y = corn_height;
+//                  t.scale.x = corn_thickness;
+//                  t.scale.z = corn_thickness;
+                    t
+                }));
+                corn.insert(collision::Collidable {
                         aabb: collision::WorldAabb {
                             min: Vec3::new(x - corn_thickness, 0.0, z - corn_thickness),
                             max: Vec3::new(x + corn_thickness, 0.0, z + corn_thickness),
@@ -136,11 +168,13 @@ fn spawn_corn(
                         animation_set: false,
                         random: rng.gen_range(0.2..0.5),
                     });
+                if let Some(corn_scene) = &corn_scene {
+                    corn.insert(corn_scene.clone());
                 }
             }
-            visibility.is_visible = false; // hide the plane underneath the corn
-            maze_plane.spawned = true;
         }
+        visibility.is_visible = false; // hide the plane underneath the corn
+        maze_plane.spawned = true;
     }
 
     component_adder.has_linked = false;
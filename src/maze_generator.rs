@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+use noise::{NoiseFn, Perlin, Seedable};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use crate::collision;
+
+// Builds corn layouts instead of reading them from maze.glb. A maze is carved out of solid corn
+// with a recursive backtracker, then thinned out by the density setting and opened up with
+// clearings wherever Perlin noise is above the threshold. The same seed always gives the same field.
+
+pub struct MazeSettings {
+    // how many corn cells wide the paths are
+    pub corridor_width: usize,
+    // chance that a wall cell keeps its corn, lower values leave gaps to cut through
+    pub density: f32,
+    // noise above this becomes a clearing, noise is roughly -1 to 1
+    pub clearing_threshold: f32,
+    // how quickly the noise changes over the field, smaller means bigger clearings
+    pub clearing_scale: f32,
+}
+
+impl Default for MazeSettings {
+    fn default() -> Self {
+        MazeSettings {
+            corridor_width: 2,
+            density: 0.9,
+            clearing_threshold: 0.55,
+            clearing_scale: 0.08,
+        }
+    }
+}
+
+// Where corn stands across a maze area. Rows run along x and columns along z,
+// the same way spawn_corn lays out stalks.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CornGrid {
+    pub origin: Vec3,
+    pub cell_size: f32,
+    pub rows: usize,
+    pub columns: usize,
+    corn: Vec::<bool>,
+}
+
+impl CornGrid {
+    pub fn new(area: &collision::WorldAabb, cell_size: f32, has_corn: bool) -> Self {
+        let rows = ((area.max.x - area.min.x) / cell_size) as usize;
+        let columns = ((area.max.z - area.min.z) / cell_size) as usize;
+
+        CornGrid {
+            origin: Vec3::new(area.min.x, 0.0, area.min.z),
+            cell_size,
+            rows,
+            columns,
+            corn: vec![has_corn; rows * columns],
+        }
+    }
+
+    pub fn has_corn(&self, row: usize, column: usize) -> bool {
+        row < self.rows && column < self.columns && self.corn[row * self.columns + column]
+    }
+
+    pub fn set_corn(&mut self, row: usize, column: usize, has_corn: bool) {
+        if row < self.rows && column < self.columns {
+            self.corn[row * self.columns + column] = has_corn;
+        }
+    }
+
+    pub fn cell_center(&self, row: usize, column: usize) -> Vec3 {
+        self.origin + Vec3::new((row as f32 + 0.5) * self.cell_size, 0.0, (column as f32 + 0.5) * self.cell_size)
+    }
+
+    pub fn corn_count(&self) -> usize {
+        self.corn.iter().filter(|c| **c).count()
+    }
+}
+
+pub fn generate(area: &collision::WorldAabb, cell_size: f32, settings: &MazeSettings, seed: u64) -> CornGrid {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut grid = CornGrid::new(area, cell_size, true);
+
+    carve_paths(&mut grid, settings.corridor_width.max(1), &mut rng);
+
+    let perlin = Perlin::new().set_seed(seed as u32);
+    for row in 0..grid.rows {
+        for column in 0..grid.columns {
+            if !grid.has_corn(row, column) { continue; }
+
+            let center = grid.cell_center(row, column);
+            let noise = perlin.get([(center.x * settings.clearing_scale) as f64,
+                                    (center.z * settings.clearing_scale) as f64]) as f32;
+            let is_clearing = noise > settings.clearing_threshold;
+            let is_thinned = rng.gen::<f32>() > settings.density;
+
+            if is_clearing || is_thinned {
+                grid.set_corn(row, column, false);
+            }
+        }
+    }
+
+    grid
+}
+
+// Treats the grid as rooms corridor_width cells across separated by one cell of corn
+// and knocks down walls between rooms with a depth first search.
+fn carve_paths(grid: &mut CornGrid, corridor_width: usize, rng: &mut StdRng) {
+    let pitch = corridor_width + 1;
+    if grid.rows <= pitch || grid.columns <= pitch {
+        return;
+    }
+
+    let room_rows = (grid.rows - 1) / pitch;
+    let room_columns = (grid.columns - 1) / pitch;
+
+    let room_start = |room: usize| 1 + room * pitch;
+    let clear = |grid: &mut CornGrid, rows: std::ops::Range<usize>, columns: std::ops::Range<usize>| {
+        for row in rows {
+            for column in columns.clone() {
+                grid.set_corn(row, column, false);
+            }
+        }
+    };
+
+    let mut visited = vec![false; room_rows * room_columns];
+    let mut stack = vec![(rng.gen_range(0..room_rows), rng.gen_range(0..room_columns))];
+    visited[stack[0].0 * room_columns + stack[0].1] = true;
+    clear(grid, room_start(stack[0].0)..room_start(stack[0].0) + corridor_width,
+                room_start(stack[0].1)..room_start(stack[0].1) + corridor_width);
+
+    while let Some(&(row, column)) = stack.last() {
+        let mut neighbours = vec![];
+        if row > 0 { neighbours.push((row - 1, column)); }
+        if row + 1 < room_rows { neighbours.push((row + 1, column)); }
+        if column > 0 { neighbours.push((row, column - 1)); }
+        if column + 1 < room_columns { neighbours.push((row, column + 1)); }
+        neighbours.retain(|(r, c)| !visited[r * room_columns + c]);
+
+        match neighbours.choose(rng) {
+            Some(&(next_row, next_column)) => {
+                visited[next_row * room_columns + next_column] = true;
+
+                // the room itself and the wall between it and where we came from
+                let rows = room_start(row.min(next_row))..room_start(row.max(next_row)) + corridor_width;
+                let columns = room_start(column.min(next_column))..room_start(column.max(next_column)) + corridor_width;
+                clear(grid, rows, columns);
+
+                stack.push((next_row, next_column));
+            },
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    // open both ends facing the goals so there's a way in and out
+    for _ in 0..2 {
+        let room = rng.gen_range(0..room_rows);
+        clear(grid, room_start(room)..room_start(room) + corridor_width, 0..room_start(0));
+    }
+    for _ in 0..2 {
+        let room = rng.gen_range(0..room_rows);
+        let last_column = room_start(room_columns - 1) + corridor_width;
+        clear(grid, room_start(room)..room_start(room) + corridor_width, last_column..grid.columns);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{maze, pathing};
+
+    // the corn in a grid as spawn_corn lays it out, stalks are 0.8 either side of their cell
+    fn corn_aabbs(grid: &CornGrid) -> Vec::<collision::WorldAabb> {
+        let mut aabbs = vec![];
+        for row in 0..grid.rows {
+            for column in 0..grid.columns {
+                if !grid.has_corn(row, column) { continue; }
+
+                let center = grid.cell_center(row, column);
+                aabbs.push(collision::WorldAabb {
+                    min: center - Vec3::new(0.8, 0.0, 0.8),
+                    max: center + Vec3::new(0.8, 0.0, 0.8),
+                });
+            }
+        }
+
+        aabbs
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_field() {
+        let settings = MazeSettings::default();
+        let first = generate(&maze::field_area(), 1.5, &settings, 42);
+        let second = generate(&maze::field_area(), 1.5, &settings, 42);
+        let other = generate(&maze::field_area(), 1.5, &settings, 43);
+
+        assert!(first.corn_count() > 0);
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn generated_fields_can_be_walked_from_one_end_zone_to_the_other() {
+        let settings = MazeSettings::default();
+        // both corn spacings, low and high graphics
+        for cell_size in [1.5, 0.8] {
+            for seed in 0..20 {
+                let grid = generate(&maze::field_area(), cell_size, &settings, seed);
+                let aabbs = corn_aabbs(&grid);
+                let path_grid = pathing::PathGrid::from_aabbs(pathing::PATH_CELL_SIZE, aabbs.iter());
+
+                assert!(path_grid.is_solvable(), "seed {} with {} spacing isn't solvable", seed, cell_size);
+            }
+        }
+    }
+}
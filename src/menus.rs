@@ -2,6 +2,8 @@ use bevy::prelude::*;
 
 pub const NORMAL_BUTTON: Color = Color::rgba(1.00, 1.00, 1.00, 0.0);
 pub const HOVERED_BUTTON: Color = Color::rgb(0.43, 0.47, 0.52);
+// behind menus opened over a round that's still on screen
+pub const OVERLAY_BACKGROUND: Color = Color::rgba(0.0, 0.0, 0.0, 0.75);
 pub const DEFAULT_FONT_SIZE: f32 = 90.0;
 pub const BY_LINE_FONT_SIZE: f32 = 45.0;
 pub const FOLLOW_FONT_SIZE: f32 = 40.0;
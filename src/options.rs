@@ -1,6 +1,7 @@
 use crate::{
     asset_loading, assets::GameAssets, audio::GameAudio, cleanup, 
-    game_controller, game_state, menus, title_screen::MenuAction, ui::text_size, AppState,
+    game_state, menus, title_screen::MenuAction, ui::text_size, AppState, rng,
+    localization::{Localization, LocalizedText}, settings::Settings,
 };
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
@@ -10,28 +11,34 @@ impl Plugin for OptionsMenuPlugin {
     fn build(&self, app: &mut App) {
         app.add_system_set(
             SystemSet::on_enter(AppState::Options)
-                .with_system(setup)
-                .with_system(game_controller::clear_presses),
+                .with_system(reset_current_option)
+                .with_system(setup),
         )
         .insert_resource(CurrentOption(0))
+        .insert_resource(OpenedFromPause(false))
         .insert_resource(OptionState::default())
         .add_event::<OptionChangeEvent>()
         .add_system_set(
             SystemSet::on_update(AppState::Options)
-                .with_system(update_menu_buttons.after("handle_input"))
+                .with_system(update_menu_buttons)
                 .with_system(highlight_options)
                 .with_system(handle_option_changes)
-                .with_system(display_current_options)
-                .with_system(
-                    handle_controllers
-                        .label("handle_input")
-                        .after(game_controller::store_controller_inputs),
-                ),
+                .with_system(handle_state_rows)
+                .with_system(display_current_options),
         )
+        // the controls screen is pushed on top, the menu comes back on the Controls row
         .add_system_set(
-            SystemSet::on_exit(AppState::Options)
+            SystemSet::on_pause(AppState::Options)
                 .with_system(cleanup::<CleanupMarker>)
-                .with_system(game_controller::clear_presses),
+        )
+        .add_system_set(
+            SystemSet::on_resume(AppState::Options)
+                .with_system(setup),
+        )
+        .add_system_set(
+            SystemSet::on_exit(AppState::Options)
+                .with_system(save_settings)
+                .with_system(cleanup::<CleanupMarker>),
         );
     }
 }
@@ -39,6 +46,23 @@ impl Plugin for OptionsMenuPlugin {
 #[derive(Component)]
 struct CleanupMarker;
 
+// the rows with a value to change, in order, then the Controls button and Start Game/Back
+const OPTION_ROWS: [&str; 8] = [
+    "options.graphics",
+    "options.shadows",
+    "options.version",
+    "options.language",
+    "options.music",
+    "options.sound",
+    "options.talk",
+    "options.display",
+];
+const CONTROLS_ROW: usize = OPTION_ROWS.len();
+const LAST_ROW: usize = CONTROLS_ROW + 1;
+
+// volumes are stepped in tenths
+const VOLUME_STEPS: usize = 10;
+
 #[derive(Component, Clone)]
 struct OptionRow {
     row: usize,
@@ -46,6 +70,10 @@ struct OptionRow {
 
 struct CurrentOption(usize);
 
+// pushed over a paused round instead of coming from the title screen, the round is still on
+// screen underneath and the last row goes back to it instead of starting a new game
+struct OpenedFromPause(bool);
+
 enum OptionChange {
     Increase,
     Decrease,
@@ -57,6 +85,10 @@ pub struct OptionState {
     graphics: usize,
     shadows_on: usize,
     game_version: usize,
+    music_volume: usize,
+    sound_volume: usize,
+    talk_volume: usize,
+    fullscreen: usize,
 }
 
 impl OptionState {
@@ -65,8 +97,50 @@ impl OptionState {
             graphics: 0,
             shadows_on: 0,
             game_version: 0,
+            music_volume: VOLUME_STEPS / 2,
+            sound_volume: VOLUME_STEPS / 5,
+            talk_volume: VOLUME_STEPS / 5,
+            fullscreen: 0,
+        }
+    }
+
+    pub fn from_settings(settings: &Settings) -> Self {
+        OptionState {
+            graphics: if settings.graphics_high { 0 } else { 1 },
+            shadows_on: if settings.shadows_on { 0 } else { 1 },
+            game_version: if settings.is_latest { 1 } else { 0 },
+            music_volume: to_steps(settings.music_volume),
+            sound_volume: to_steps(settings.sound_volume),
+            talk_volume: to_steps(settings.talk_volume),
+            fullscreen: if settings.fullscreen { 0 } else { 1 },
         }
     }
+
+    // the volumes and window mode take effect as soon as they're changed, everything else
+    // waits for the next round
+    fn apply(&self, settings: &mut Settings) {
+        settings.graphics_high = self.graphics == 0;
+        settings.shadows_on = self.shadows_on == 0;
+        settings.is_latest = self.game_version != 0;
+        settings.music_volume = self.music_volume as f32 / VOLUME_STEPS as f32;
+        settings.sound_volume = self.sound_volume as f32 / VOLUME_STEPS as f32;
+        settings.talk_volume = self.talk_volume as f32 / VOLUME_STEPS as f32;
+        settings.fullscreen = self.fullscreen == 0;
+    }
+}
+
+fn to_steps(volume: f32) -> usize {
+    (volume.clamp(0.0, 1.0) * VOLUME_STEPS as f32).round() as usize
+}
+
+fn save_settings(
+    options: Res<OptionState>,
+    localization: Res<Localization>,
+    mut settings: ResMut<Settings>,
+) {
+    options.apply(&mut settings);
+    settings.locale = localization.locale().to_string();
+    settings.save();
 }
 
 #[derive(Component)]
@@ -76,19 +150,28 @@ struct OptionChangeEvent {
     action: OptionChange,
 }
 
+fn reset_current_option(mut current_option: ResMut<CurrentOption>) {
+    current_option.0 = 0;
+}
+
 fn setup(
     mut commands: Commands,
     game_assets: Res<GameAssets>,
-    mut current_option: ResMut<CurrentOption>,
+    mut opened_from_pause: ResMut<OpenedFromPause>,
+    app_state: Res<State<AppState>>,
     text_scaler: text_size::TextScaler,
+    localization: Res<Localization>,
 ) {
-    current_option.0 = 0;
+    opened_from_pause.0 = app_state.inactives().contains(&AppState::Pause);
 
-    commands
-        .spawn_bundle(Camera3dBundle {
-            ..Default::default()
-        })
-        .insert(CleanupMarker);
+    // the round's camera is still there when paused
+    if !opened_from_pause.0 {
+        commands
+            .spawn_bundle(Camera3dBundle {
+                ..Default::default()
+            })
+            .insert(CleanupMarker);
+    }
 
     commands
         .spawn_bundle(InputManagerBundle {
@@ -108,7 +191,7 @@ fn setup(
                 flex_direction: FlexDirection::ColumnReverse,
                 ..Default::default()
             },
-            color: Color::NONE.into(),
+            color: if opened_from_pause.0 { menus::OVERLAY_BACKGROUND.into() } else { Color::NONE.into() },
             ..Default::default()
         })
         .insert(CleanupMarker)
@@ -116,7 +199,7 @@ fn setup(
             parent
                 .spawn_bundle(NodeBundle {
                     style: Style {
-                        size: Size::new(Val::Percent(100.0), Val::Percent(20.0)),
+                        size: Size::new(Val::Percent(100.0), Val::Percent(14.0)),
                         position_type: PositionType::Relative,
                         justify_content: JustifyContent::Center,
                         align_items: AlignItems::FlexEnd,
@@ -128,219 +211,149 @@ fn setup(
                 .with_children(|parent| {
                     add_title(
                         parent,
-                        game_assets.font.clone(),
+                        localization.font(&game_assets),
                         text_scaler.scale(menus::DEFAULT_FONT_SIZE * 1.2),
-                        "Game Settings",
-                        Vec::<CleanupMarker>::new(), // just an empty vec since can't do <impl Trait>
+                        &localization.get("options.title"),
+                        vec![LocalizedText("options.title".to_string())],
                     );
                 });
 
+            for (row, key) in OPTION_ROWS.iter().enumerate() {
+                add_option_row(
+                    parent,
+                    &localization,
+                    &game_assets,
+                    text_scaler.scale(menus::DEFAULT_FONT_SIZE),
+                    text_scaler.scale(menus::SCORE_FONT_SIZE),
+                    key,
+                    row,
+                );
+            }
+
             parent
                 .spawn_bundle(NodeBundle {
                     style: Style {
-                        size: Size::new(Val::Percent(100.0), Val::Percent(15.0)),
+                        size: Size::new(Val::Percent(40.0), Val::Percent(8.0)),
                         position_type: PositionType::Relative,
-                        align_items: AlignItems::FlexEnd,
+                        margin: UiRect {
+                            left: Val::Auto,
+                            right: Val::Auto,
+                            top: Val::Percent(2.0),
+                            ..Default::default()
+                        },
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::FlexStart,
                         ..Default::default()
                     },
                     color: Color::NONE.into(),
                     ..Default::default()
                 })
-                .insert(OptionRow { row: 0 })
+                .insert(OptionRow { row: CONTROLS_ROW })
                 .with_children(|parent| {
-                    parent
-                        .spawn_bundle(NodeBundle {
-                            style: Style {
-                                size: Size::new(Val::Percent(50.0), Val::Percent(100.0)),
-                                position_type: PositionType::Relative,
-                                justify_content: JustifyContent::Center,
-                                align_items: AlignItems::FlexEnd,
-                                ..Default::default()
-                            },
-                            color: Color::NONE.into(),
-                            ..Default::default()
-                        })
-                        .with_children(|parent| {
-                            add_label(
-                                parent,
-                                game_assets.font.clone(),
-                                text_scaler.scale(menus::DEFAULT_FONT_SIZE),
-                                "Graphics   :",
-                                vec![OptionRow { row: 0 }],
-                            );
-                        });
-                    parent
-                        .spawn_bundle(NodeBundle {
-                            style: Style {
-                                size: Size::new(Val::Percent(50.0), Val::Percent(100.0)),
-                                position_type: PositionType::Relative,
-                                justify_content: JustifyContent::Center,
-                                align_items: AlignItems::FlexEnd,
-                                ..Default::default()
-                            },
-                            color: Color::NONE.into(),
-                            ..Default::default()
-                        })
-                        .with_children(|parent| {
-                            add_option(
-                                parent,
-                                game_assets.font.clone(),
-                                text_scaler.scale(menus::SCORE_FONT_SIZE),
-                                vec![OptionRow { row: 0 }],
-                            );
-                        });
+                    add_button(
+                        parent,
+                        &localization,
+                        &game_assets,
+                        text_scaler.scale(menus::SCORE_FONT_SIZE),
+                        "options.controls",
+                        vec![OptionRow { row: CONTROLS_ROW }],
+                    );
                 });
 
             parent
                 .spawn_bundle(NodeBundle {
                     style: Style {
-                        size: Size::new(Val::Percent(100.0), Val::Percent(15.0)),
+                        size: Size::new(Val::Percent(40.0), Val::Percent(8.0)),
                         position_type: PositionType::Relative,
-                        align_items: AlignItems::FlexEnd,
+                        margin: UiRect {
+                            left: Val::Auto,
+                            right: Val::Auto,
+                            top: Val::Percent(1.0),
+                            ..Default::default()
+                        },
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::FlexStart,
                         ..Default::default()
                     },
                     color: Color::NONE.into(),
                     ..Default::default()
                 })
-                .insert(OptionRow { row: 1 })
+                .insert(OptionRow { row: LAST_ROW })
                 .with_children(|parent| {
-                    parent
-                        .spawn_bundle(NodeBundle {
-                            style: Style {
-                                size: Size::new(Val::Percent(50.0), Val::Percent(100.0)),
-                                position_type: PositionType::Relative,
-                                justify_content: JustifyContent::Center,
-                                align_items: AlignItems::FlexEnd,
-                                ..Default::default()
-                            },
-                            color: Color::NONE.into(),
-                            ..Default::default()
-                        })
-                        .with_children(|parent| {
-                            add_label(
-                                parent,
-                                game_assets.font.clone(),
-                                text_scaler.scale(menus::DEFAULT_FONT_SIZE),
-                                "Shadows    :",
-                                vec![OptionRow { row: 1 }],
-                            );
-                        });
-
-                    parent
-                        .spawn_bundle(NodeBundle {
-                            style: Style {
-                                size: Size::new(Val::Percent(50.0), Val::Percent(100.0)),
-                                position_type: PositionType::Relative,
-                                justify_content: JustifyContent::Center,
-                                align_items: AlignItems::FlexEnd,
-                                ..Default::default()
-                            },
-                            color: Color::NONE.into(),
-                            ..Default::default()
-                        })
-                        .with_children(|parent| {
-                            add_option(
-                                parent,
-                                game_assets.font.clone(),
-                                text_scaler.scale(menus::SCORE_FONT_SIZE),
-                                vec![OptionRow { row: 1 }],
-                            );
-                        });
+                    add_button(
+                        parent,
+                        &localization,
+                        &game_assets,
+                        text_scaler.scale(menus::SCORE_FONT_SIZE),
+                        if opened_from_pause.0 { "options.back" } else { "options.start_game" },
+                        vec![OptionRow { row: LAST_ROW }],
+                    );
                 });
+        });
+}
 
+fn add_option_row(
+    builder: &mut ChildBuilder<'_, '_, '_>,
+    localization: &Localization,
+    game_assets: &GameAssets,
+    label_size: f32,
+    value_size: f32,
+    key: &str,
+    row: usize,
+) {
+    builder
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(8.0)),
+                position_type: PositionType::Relative,
+                align_items: AlignItems::FlexEnd,
+                ..Default::default()
+            },
+            color: Color::NONE.into(),
+            ..Default::default()
+        })
+        .insert(OptionRow { row })
+        .with_children(|parent| {
             parent
                 .spawn_bundle(NodeBundle {
                     style: Style {
-                        size: Size::new(Val::Percent(100.0), Val::Percent(15.0)),
+                        size: Size::new(Val::Percent(50.0), Val::Percent(100.0)),
                         position_type: PositionType::Relative,
+                        justify_content: JustifyContent::Center,
                         align_items: AlignItems::FlexEnd,
                         ..Default::default()
                     },
                     color: Color::NONE.into(),
                     ..Default::default()
                 })
-                .insert(OptionRow { row: 2 })
                 .with_children(|parent| {
-                    parent
-                        .spawn_bundle(NodeBundle {
-                            style: Style {
-                                size: Size::new(Val::Percent(50.0), Val::Percent(100.0)),
-                                position_type: PositionType::Relative,
-                                justify_content: JustifyContent::Center,
-                                align_items: AlignItems::FlexEnd,
-                                ..Default::default()
-                            },
-                            color: Color::NONE.into(),
-                            ..Default::default()
-                        })
-                        .with_children(|parent| {
-                            add_label(
-                                parent,
-                                game_assets.font.clone(),
-                                text_scaler.scale(menus::DEFAULT_FONT_SIZE),
-                                "Version    :",
-                                vec![OptionRow { row: 2 }],
-                            );
-                        });
-
-                    parent
-                        .spawn_bundle(NodeBundle {
-                            style: Style {
-                                size: Size::new(Val::Percent(50.0), Val::Percent(100.0)),
-                                position_type: PositionType::Relative,
-                                justify_content: JustifyContent::Center,
-                                align_items: AlignItems::FlexEnd,
-                                ..Default::default()
-                            },
-                            color: Color::NONE.into(),
-                            ..Default::default()
-                        })
-                        .with_children(|parent| {
-                            add_option(
-                                parent,
-                                game_assets.font.clone(),
-                                text_scaler.scale(menus::SCORE_FONT_SIZE),
-                                vec![OptionRow { row: 2 }],
-                            );
-                        });
+                    add_label(parent, localization, game_assets, label_size, key, vec![OptionRow { row }]);
                 });
 
             parent
                 .spawn_bundle(NodeBundle {
                     style: Style {
-                        size: Size::new(Val::Percent(40.0), Val::Percent(20.0)),
+                        size: Size::new(Val::Percent(50.0), Val::Percent(100.0)),
                         position_type: PositionType::Relative,
-                        margin: UiRect {
-                            left: Val::Auto,
-                            right: Val::Auto,
-                            top: Val::Percent(20.0),
-                            ..Default::default()
-                        },
                         justify_content: JustifyContent::Center,
-                        align_items: AlignItems::FlexStart,
+                        align_items: AlignItems::FlexEnd,
                         ..Default::default()
                     },
                     color: Color::NONE.into(),
                     ..Default::default()
                 })
-                .insert(OptionRow { row: 3 })
                 .with_children(|parent| {
-                    add_button(
-                        parent,
-                        game_assets.font.clone(),
-                        text_scaler.scale(menus::SCORE_FONT_SIZE),
-                        "Start Game",
-                        vec![OptionRow { row: 3 }],
-                    );
+                    add_option(parent, localization.font(game_assets), value_size, vec![OptionRow { row }]);
                 });
         });
 }
 
 fn add_label(
     builder: &mut ChildBuilder<'_, '_, '_>,
-    font: Handle<Font>,
+    localization: &Localization,
+    game_assets: &GameAssets,
     font_size: f32,
-    label: &str,
+    key: &str,
     mut components: Vec<impl Component>,
 ) {
     let mut text_bundle = builder.spawn_bundle(TextBundle {
@@ -357,15 +370,16 @@ fn add_label(
             ..Default::default()
         },
         text: Text::from_section(
-            label,
+            localization.get(key),
             TextStyle {
-                font,
+                font: localization.font(game_assets),
                 font_size,
                 color: Color::WHITE,
             },
         ).with_alignment(TextAlignment::default()),
         ..Default::default()
     });
+    text_bundle.insert(LocalizedText(key.to_string()));
     components.drain(..).for_each(|c| {
         text_bundle.insert(c);
     });
@@ -465,9 +479,10 @@ fn add_option(
 
 fn add_button(
     builder: &mut ChildBuilder<'_, '_, '_>,
-    font: Handle<Font>,
+    localization: &Localization,
+    game_assets: &GameAssets,
     font_size: f32,
-    label: &str,
+    key: &str,
     mut components: Vec<impl Component>,
 ) {
     let mut text_bundle = builder.spawn_bundle(TextBundle {
@@ -479,9 +494,9 @@ fn add_button(
             ..Default::default()
         },
         text: Text::from_section(
-            label.to_string(),
+            localization.get(key),
             TextStyle {
-                font,
+                font: localization.font(game_assets),
                 font_size,
                 color: Color::WHITE,
             },
@@ -494,6 +509,7 @@ fn add_button(
         ..Default::default()
     });
 
+    text_bundle.insert(LocalizedText(key.to_string()));
     components.drain(..).for_each(|c| {
         text_bundle.insert(c);
     });
@@ -574,7 +590,7 @@ fn update_menu_buttons(
     mut option_change_event_writer: EventWriter<OptionChangeEvent>,
 ) {
     let action_state = action_state.single();
-    let max_options = 3;
+    let max_options = LAST_ROW;
 
     if action_state.just_pressed(MenuAction::Up) {
         audio.play_sfx(&game_assets.blip);
@@ -605,48 +621,18 @@ fn update_menu_buttons(
     }
 }
 
-fn handle_controllers(
-    controllers: Res<game_controller::GameController>,
-    mut players: Query<(Entity, &mut ActionState<MenuAction>)>,
-) {
-    for (_, mut action_state) in players.iter_mut() {
-        for (_, just_pressed) in controllers.just_pressed.iter() {
-            action_state.release(MenuAction::Up);
-            action_state.release(MenuAction::Down);
-            action_state.release(MenuAction::Left);
-            action_state.release(MenuAction::Right);
-
-            action_state.release(MenuAction::Select);
-
-            if just_pressed.contains(&game_controller::GameButton::Up) {
-                action_state.press(MenuAction::Up);
-            }
-            if just_pressed.contains(&game_controller::GameButton::Down) {
-                action_state.press(MenuAction::Down);
-            }
-            if just_pressed.contains(&game_controller::GameButton::Left) {
-                action_state.press(MenuAction::Left);
-            }
-            if just_pressed.contains(&game_controller::GameButton::Right) {
-                action_state.press(MenuAction::Right);
-            }
-            if just_pressed.contains(&game_controller::GameButton::ActionDown)
-                || just_pressed.contains(&game_controller::GameButton::Start)
-            {
-                action_state.press(MenuAction::Select);
-            }
-        }
-    }
-}
-
 fn handle_option_changes(
     current_option: Res<CurrentOption>,
+    opened_from_pause: Res<OpenedFromPause>,
     mut option_change_event_reader: EventReader<OptionChangeEvent>,
     mut options: ResMut<OptionState>,
     mut game_assets: ResMut<GameAssets>,
     mut game_state: ResMut<game_state::GameState>,
     mut assets_handler: asset_loading::AssetsHandler,
     mut audio: GameAudio,
+    mut game_rng: ResMut<rng::GameRng>,
+    mut localization: ResMut<Localization>,
+    mut settings: ResMut<Settings>,
 ) {
     for option_change in option_change_event_reader.iter() {
         match current_option.0 {
@@ -702,8 +688,48 @@ fn handle_option_changes(
                 };
             },
             3 => {
+                match option_change.action {
+                    OptionChange::Increase => {
+                        localization.cycle_locale(true);
+                        audio.play_sfx(&game_assets.blip);
+                    }
+                    OptionChange::Decrease => {
+                        localization.cycle_locale(false);
+                        audio.play_sfx(&game_assets.blip);
+                    }
+                    _ => (),
+                };
+            },
+            4 => {
+                if step_volume(&mut options.music_volume, &option_change.action) {
+                    audio.play_sfx(&game_assets.blip);
+                }
+            },
+            5 => {
+                if step_volume(&mut options.sound_volume, &option_change.action) {
+                    audio.play_sfx(&game_assets.blip);
+                }
+            },
+            6 => {
+                if step_volume(&mut options.talk_volume, &option_change.action) {
+                    audio.play_sfx(&game_assets.blip);
+                }
+            },
+            7 => {
+                match option_change.action {
+                    OptionChange::Increase | OptionChange::Decrease => {
+                        options.fullscreen = if options.fullscreen == 0 { 1 } else { 0 };
+                        audio.play_sfx(&game_assets.blip);
+                    }
+                    _ => (),
+                };
+            },
+            LAST_ROW if !opened_from_pause.0 => {
                 if let OptionChange::Select = option_change.action {
+                    let versus = game_state.versus;
                     *game_state = game_state::GameState::initialize(options.graphics == 0, options.shadows_on == 0, options.game_version != 0);
+                    game_state.versus = versus;
+                    game_rng.start_run();
 
                     audio.play_sfx(&game_assets.blip);
                     assets_handler.load(AppState::InGame, &mut game_assets, &mut game_state);
@@ -711,33 +737,82 @@ fn handle_option_changes(
             }
             _ => (),
         }
+
+        options.apply(&mut settings);
+        audio.set_volume(&settings);
+    }
+}
+
+// returns whether the volume moved, it stops at either end instead of wrapping around
+fn step_volume(volume: &mut usize, action: &OptionChange) -> bool {
+    let stepped = match action {
+        OptionChange::Increase => (*volume + 1).min(VOLUME_STEPS),
+        OptionChange::Decrease => volume.saturating_sub(1),
+        OptionChange::Select => *volume,
+    };
+    let changed = stepped != *volume;
+    *volume = stepped;
+    changed
+}
+
+// rows that move the state stack, AssetsHandler already holds the state in handle_option_changes
+fn handle_state_rows(
+    current_option: Res<CurrentOption>,
+    opened_from_pause: Res<OpenedFromPause>,
+    mut option_change_event_reader: EventReader<OptionChangeEvent>,
+    mut app_state: ResMut<State<AppState>>,
+    game_assets: Res<GameAssets>,
+    mut audio: GameAudio,
+) {
+    let selected = option_change_event_reader.iter().any(|option_change| matches!(option_change.action, OptionChange::Select));
+    if !selected { return; }
+
+    match current_option.0 {
+        CONTROLS_ROW => {
+            audio.play_sfx(&game_assets.blip);
+            app_state.push(AppState::Controls).unwrap();
+        },
+        LAST_ROW if opened_from_pause.0 => {
+            audio.play_sfx(&game_assets.blip);
+            app_state.pop().unwrap();
+        },
+        _ => (),
     }
 }
 
 fn display_current_options(
     option_state: ResMut<OptionState>,
     mut options: Query<(&mut Text, &OptionRow), With<OptionValueMarker>>,
+    localization: Res<Localization>,
+    game_assets: Res<GameAssets>,
 ) {
     for (mut option_text, option_row) in options.iter_mut() {
-        if option_row.row == 0 {
-            option_text.sections[0].value = match option_state.graphics {
-                0 => " High ".to_string(),
-                _ => " Low  ".to_string(),
-            };
-        }
-
-        if option_row.row == 1 {
-            option_text.sections[0].value = match option_state.shadows_on {
-                0 => "  On  ".to_string(),
-                _ => "  Off ".to_string(),
-            };
-        }
+        let value = match option_row.row {
+            0 => match option_state.graphics {
+                0 => localization.get("options.high"),
+                _ => localization.get("options.low"),
+            },
+            1 => match option_state.shadows_on {
+                0 => localization.get("options.on"),
+                _ => localization.get("options.off"),
+            },
+            2 => match option_state.game_version {
+                0 => localization.get("options.jam"),
+                _ => localization.get("options.latest"),
+            },
+            3 => localization.language_name(),
+            4 => format!("{}%", option_state.music_volume * 100 / VOLUME_STEPS),
+            5 => format!("{}%", option_state.sound_volume * 100 / VOLUME_STEPS),
+            6 => format!("{}%", option_state.talk_volume * 100 / VOLUME_STEPS),
+            7 => match option_state.fullscreen {
+                0 => localization.get("options.fullscreen"),
+                _ => localization.get("options.windowed"),
+            },
+            _ => continue,
+        };
 
-        if option_row.row == 2 {
-            option_text.sections[0].value = match option_state.game_version {
-                0 => " Jam  ".to_string(),
-                _ => "Latest".to_string(),
-            };
-        }
+        // the values all take up the same width so the arrows either side don't jump around
+        option_text.sections[0].value = format!("{:^6}", value);
+        option_text.sections[0].style.font = localization.font(&game_assets);
     }
 }
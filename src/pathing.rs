@@ -0,0 +1,465 @@
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use crate::{
+    collision, timestep,
+    LEFT_END, RIGHT_END, LEFT_GOAL, RIGHT_GOAL, BOTTOM_END, TOP_END,
+};
+
+// A walkability grid over the whole field built from the static Collidables, used to check
+// that the corn can be walked through from one goal to the other, that footballs don't
+// land somewhere the player can't get to and to find routes for enemies. Harvested corn loses
+// its Collidable, so the grid is rebuilt whenever the collision grid changes and paths open up
+// as the combine clears the field.
+
+pub struct PathingPlugin;
+impl Plugin for PathingPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(PathGrid::new(PATH_CELL_SIZE))
+            .init_resource::<MazeReport>()
+            .add_system_to_stage(
+                timestep::FixedUpdateStage,
+                update_path_grid.exclusive_system().at_start().after(collision::UpdateCollisionGrid)
+            )
+            .add_system_to_stage(CoreStage::PostUpdate, update_path_grid)
+            .add_system_to_stage(CoreStage::PostUpdate, update_maze_report.after(update_path_grid));
+    }
+}
+
+pub const PATH_CELL_SIZE: f32 = 0.5;
+// how far out to look for an open cell when a path starts or ends inside corn
+const MAX_OPEN_CELL_SEARCH: usize = 8;
+
+pub type Cell = (usize, usize);
+
+// Rows run along x and columns along z like CornGrid
+#[derive(Clone, Debug)]
+pub struct PathGrid {
+    pub origin: Vec3,
+    pub cell_size: f32,
+    pub rows: usize,
+    pub columns: usize,
+    blocked: Vec::<bool>,
+    version: Option<u64>,
+}
+
+impl PathGrid {
+    pub fn new(cell_size: f32) -> Self {
+        let rows = ((TOP_END - BOTTOM_END) / cell_size).ceil() as usize;
+        let columns = ((RIGHT_END - LEFT_END) / cell_size).ceil() as usize;
+
+        PathGrid {
+            origin: Vec3::new(BOTTOM_END, 0.0, LEFT_END),
+            cell_size,
+            rows,
+            columns,
+            blocked: vec![false; rows * columns],
+            version: None,
+        }
+    }
+
+    // a cell is blocked when something standing at its center would be inside a Collidable
+    pub fn from_aabbs<'a>(cell_size: f32, aabbs: impl Iterator<Item = &'a collision::WorldAabb>) -> Self {
+        let mut grid = PathGrid::new(cell_size);
+        grid.block_aabbs(aabbs);
+        grid
+    }
+
+    fn block_aabbs<'a>(&mut self, aabbs: impl Iterator<Item = &'a collision::WorldAabb>) {
+        self.blocked.iter_mut().for_each(|b| *b = false);
+
+        for aabb in aabbs {
+            for cell in self.cells_under(aabb) {
+                let index = self.index(cell);
+                self.blocked[index] = true;
+            }
+        }
+    }
+
+    // Works out again whether the cells under an area are blocked, for when something there was
+    // added, moved or taken away. Anything else still covering those cells keeps them blocked.
+    fn refresh_area(&mut self, area: &collision::WorldAabb, collision_grid: &collision::CollisionGrid) {
+        let reach = Vec3::splat(collision::MOVER_RADIUS);
+        for cell in self.cells_under(area) {
+            let center = self.cell_center(cell);
+            let blocked = collision_grid.query(&collision::WorldAabb { min: center - reach, max: center + reach })
+                                        .into_iter()
+                                        .filter_map(|entity| collision_grid.aabb(entity))
+                                        .any(|aabb| self.cells_under(aabb).any(|other| other == cell));
+            self.set_blocked(cell, blocked);
+        }
+    }
+
+    // cells whose centers fall in the box once it's grown by the mover's radius
+    fn cells_under(&self, aabb: &collision::WorldAabb) -> impl Iterator<Item = Cell> {
+        let min = aabb.min - Vec3::splat(collision::MOVER_RADIUS);
+        let max = aabb.max + Vec3::splat(collision::MOVER_RADIUS);
+
+        let cell_size = self.cell_size;
+        let first = |low: f32, origin: f32| ((low - origin) / cell_size - 0.5).ceil().max(0.0) as usize;
+        let last = |high: f32, origin: f32| ((high - origin) / cell_size - 0.5).floor();
+
+        let max_row = last(max.x, self.origin.x);
+        let max_column = last(max.z, self.origin.z);
+        let (rows, columns) = if max_row < 0.0 || max_column < 0.0 {
+            (1..=0, 1..=0)
+        } else {
+            (first(min.x, self.origin.x)..=(max_row as usize).min(self.rows.saturating_sub(1)),
+             first(min.z, self.origin.z)..=(max_column as usize).min(self.columns.saturating_sub(1)))
+        };
+
+        rows.flat_map(move |row| columns.clone().map(move |column| (row, column)))
+    }
+
+    pub fn cell(&self, position: Vec3) -> Option<Cell> {
+        let row = ((position.x - self.origin.x) / self.cell_size).floor();
+        let column = ((position.z - self.origin.z) / self.cell_size).floor();
+        if row < 0.0 || column < 0.0 || row as usize >= self.rows || column as usize >= self.columns {
+            return None;
+        }
+
+        Some((row as usize, column as usize))
+    }
+
+    pub fn cell_center(&self, (row, column): Cell) -> Vec3 {
+        self.origin + Vec3::new((row as f32 + 0.5) * self.cell_size, 0.0, (column as f32 + 0.5) * self.cell_size)
+    }
+
+    pub fn is_blocked(&self, (row, column): Cell) -> bool {
+        row >= self.rows || column >= self.columns || self.blocked[row * self.columns + column]
+    }
+
+    pub fn set_blocked(&mut self, (row, column): Cell, blocked: bool) {
+        if row < self.rows && column < self.columns {
+            self.blocked[row * self.columns + column] = blocked;
+        }
+    }
+
+    // the collision grid version this was built from, changes whenever corn is harvested
+    pub fn version(&self) -> Option<u64> {
+        self.version
+    }
+
+    pub fn index(&self, (row, column): Cell) -> usize {
+        row * self.columns + column
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = Cell> {
+        let columns = self.columns;
+        (0..self.rows).flat_map(move |row| (0..columns).map(move |column| (row, column)))
+    }
+
+    // open cells next to this one, no diagonals so paths can't squeeze between two corners
+    pub fn neighbours(&self, (row, column): Cell) -> impl Iterator<Item = Cell> + '_ {
+        let mut neighbours = Vec::<Cell>::with_capacity(4);
+        if row > 0 { neighbours.push((row - 1, column)); }
+        if row + 1 < self.rows { neighbours.push((row + 1, column)); }
+        if column > 0 { neighbours.push((row, column - 1)); }
+        if column + 1 < self.columns { neighbours.push((row, column + 1)); }
+
+        neighbours.into_iter().filter(move |cell| !self.is_blocked(*cell))
+    }
+
+    // every open cell that can be walked to from any of the starting cells
+    pub fn flood(&self, starts: impl Iterator<Item = Cell>) -> Vec::<bool> {
+        let mut reached = vec![false; self.rows * self.columns];
+        let mut queue = VecDeque::<Cell>::new();
+        for start in starts {
+            if !self.is_blocked(start) && !reached[self.index(start)] {
+                reached[self.index(start)] = true;
+                queue.push_back(start);
+            }
+        }
+
+        while let Some(cell) = queue.pop_front() {
+            for next in self.neighbours(cell) {
+                if !reached[self.index(next)] {
+                    reached[self.index(next)] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        reached
+    }
+
+    fn end_zone(&self, left: bool) -> impl Iterator<Item = Cell> + '_ {
+        self.cells().filter(move |cell| {
+            let z = self.cell_center(*cell).z;
+            if left { z <= LEFT_GOAL } else { z >= RIGHT_GOAL }
+        })
+    }
+
+    // true if there's a way from the left end zone to the right one
+    pub fn is_solvable(&self) -> bool {
+        let reached = self.flood(self.end_zone(true));
+        self.end_zone(false).any(|cell| reached[self.index(cell)])
+    }
+
+    pub fn is_reachable(&self, from: Vec3, to: Vec3) -> bool {
+        match (self.cell(from), self.cell(to)) {
+            (Some(from), Some(to)) => self.flood(std::iter::once(from))[self.index(to)],
+            _ => false,
+        }
+    }
+
+    // the cell itself if it's open, otherwise the closest open cell around it
+    pub fn nearest_open(&self, (row, column): Cell) -> Option<Cell> {
+        if !self.is_blocked((row, column)) {
+            return Some((row, column));
+        }
+
+        for radius in 1..=MAX_OPEN_CELL_SEARCH {
+            let rows = row.saturating_sub(radius)..=(row + radius).min(self.rows.saturating_sub(1));
+            let columns = column.saturating_sub(radius)..=(column + radius).min(self.columns.saturating_sub(1));
+
+            let ring = rows.flat_map(|r| columns.clone().map(move |c| (r, c)))
+                           .filter(|(r, c)| r.abs_diff(row) == radius || c.abs_diff(column) == radius);
+            let closest = ring.filter(|cell| !self.is_blocked(*cell))
+                              .min_by_key(|(r, c)| r.abs_diff(row).pow(2) + c.abs_diff(column).pow(2));
+            if closest.is_some() {
+                return closest;
+            }
+        }
+
+        None
+    }
+
+    // A* from one position to another. The waypoints are cell centers with the ones along
+    // straight runs left out, ending on the target itself when it's in an open cell.
+    pub fn find_path(&self, from: Vec3, to: Vec3) -> Option<Vec::<Vec3>> {
+        let start = self.nearest_open(self.cell(from)?)?;
+        let target_cell = self.cell(to)?;
+        let goal = self.nearest_open(target_cell)?;
+
+        let heuristic = |(row, column): Cell| row.abs_diff(goal.0) + column.abs_diff(goal.1);
+
+        let mut came_from = vec![None; self.rows * self.columns];
+        let mut costs = vec![usize::MAX; self.rows * self.columns];
+        let mut open = BinaryHeap::new();
+        costs[self.index(start)] = 0;
+        open.push(Reverse((heuristic(start), 0, start)));
+
+        while let Some(Reverse((_, cost, cell))) = open.pop() {
+            if cell == goal {
+                let mut cells = vec![cell];
+                while let Some(previous) = came_from[self.index(*cells.last().unwrap())] {
+                    cells.push(previous);
+                }
+                cells.reverse();
+
+                return Some(self.waypoints(&cells, if goal == target_cell { Some(to) } else { None }));
+            }
+
+            // already found a cheaper way here
+            if cost > costs[self.index(cell)] { continue; }
+
+            for next in self.neighbours(cell) {
+                let next_cost = cost + 1;
+                if next_cost < costs[self.index(next)] {
+                    costs[self.index(next)] = next_cost;
+                    came_from[self.index(next)] = Some(cell);
+                    open.push(Reverse((next_cost + heuristic(next), next_cost, next)));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn waypoints(&self, cells: &[Cell], end: Option<Vec3>) -> Vec::<Vec3> {
+        let step = |a: Cell, b: Cell| (b.0 as i64 - a.0 as i64, b.1 as i64 - a.1 as i64);
+
+        let mut waypoints = Vec::<Vec3>::new();
+        // the first cell is where the mover already is
+        for i in 1..cells.len() {
+            let is_turn = i + 1 < cells.len() && step(cells[i - 1], cells[i]) != step(cells[i], cells[i + 1]);
+            let is_last = i + 1 == cells.len();
+            if is_turn || is_last {
+                waypoints.push(self.cell_center(cells[i]));
+            }
+        }
+
+        if let Some(end) = end {
+            waypoints.pop();
+            waypoints.push(end);
+        }
+
+        waypoints
+    }
+
+    // the target if it can be walked to from the given position, otherwise the center of the
+    // closest cell that can be. None if nothing can be reached at all.
+    pub fn nearest_reachable(&self, from: Vec3, target: Vec3) -> Option<Vec3> {
+        let start = self.cell(from)?;
+        let reached = self.flood(std::iter::once(start));
+
+        if let Some(cell) = self.cell(target) {
+            if reached[self.index(cell)] {
+                return Some(target);
+            }
+        }
+
+        self.cells()
+            .filter(|cell| reached[self.index(*cell)])
+            .map(|cell| self.cell_center(cell))
+            .min_by(|a, b| a.distance_squared(target).total_cmp(&b.distance_squared(target)))
+    }
+}
+
+#[derive(Default)]
+pub struct MazeReport {
+    pub solvable: bool,
+    pub open_cells: usize,
+    pub reachable_from_left: usize,
+}
+
+impl MazeReport {
+    pub fn new(grid: &PathGrid) -> Self {
+        let reached = grid.flood(grid.end_zone(true));
+        MazeReport {
+            solvable: grid.end_zone(false).any(|cell| reached[grid.index(cell)]),
+            open_cells: grid.cells().filter(|cell| !grid.is_blocked(*cell)).count(),
+            reachable_from_left: reached.iter().filter(|r| **r).count(),
+        }
+    }
+}
+
+fn update_path_grid(
+    mut path_grid: ResMut<PathGrid>,
+    mut collision_grid: ResMut<collision::CollisionGrid>,
+) {
+    if path_grid.version == Some(collision_grid.version()) { return; }
+
+    // harvesting only changes a few cells at a time, there's no need to go over the whole field
+    match collision_grid.take_changes() {
+        Some(changes) if path_grid.version.is_some() => {
+            for area in changes.iter() {
+                path_grid.refresh_area(area, &collision_grid);
+            }
+        },
+        _ => path_grid.block_aabbs(collision_grid.aabbs()),
+    }
+    path_grid.version = Some(collision_grid.version());
+}
+
+// flooding the whole field is left out of the gameplay steps and done at most once a frame
+fn update_maze_report(
+    path_grid: Res<PathGrid>,
+    mut maze_report: ResMut<MazeReport>,
+) {
+    if !path_grid.is_changed() { return; }
+
+    let report = MazeReport::new(&path_grid);
+    if report.solvable != maze_report.solvable {
+        println!("maze is {} ({} of {} open cells reachable from the left goal)",
+                 if report.solvable { "solvable" } else { "not solvable" },
+                 report.reachable_from_left, report.open_cells);
+    }
+    *maze_report = report;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collision::WorldAabb;
+
+    fn aabb(min: (f32, f32), max: (f32, f32)) -> WorldAabb {
+        WorldAabb {
+            min: Vec3::new(min.0, 0.0, min.1),
+            max: Vec3::new(max.0, 2.0, max.1),
+        }
+    }
+
+    fn left_goal() -> Vec3 {
+        Vec3::new(0.0, 0.0, LEFT_GOAL)
+    }
+
+    fn right_goal() -> Vec3 {
+        Vec3::new(0.0, 0.0, RIGHT_GOAL)
+    }
+
+    #[test]
+    fn an_empty_field_is_solvable() {
+        let grid = PathGrid::new(PATH_CELL_SIZE);
+        assert!(grid.is_solvable());
+        assert!(grid.is_reachable(left_goal(), right_goal()));
+    }
+
+    #[test]
+    fn a_wall_built_by_hand_blocks_the_field_until_it_has_a_gap() {
+        let mut grid = PathGrid::new(PATH_CELL_SIZE);
+        let (_, column) = grid.cell(Vec3::ZERO).unwrap();
+        for row in 0..grid.rows {
+            grid.set_blocked((row, column), true);
+        }
+
+        assert!(!grid.is_solvable());
+        assert!(!grid.is_reachable(left_goal(), right_goal()));
+
+        grid.set_blocked((grid.rows / 2, column), false);
+        assert!(grid.is_solvable());
+        assert!(grid.is_reachable(left_goal(), right_goal()));
+    }
+
+    #[test]
+    fn corn_across_the_whole_field_blocks_it() {
+        let wall = [aabb((BOTTOM_END - 1.0, -0.5), (TOP_END + 1.0, 0.5))];
+        let grid = PathGrid::from_aabbs(PATH_CELL_SIZE, wall.iter());
+
+        assert!(!grid.is_solvable());
+        assert!(!grid.is_reachable(left_goal(), right_goal()));
+    }
+
+    #[test]
+    fn corn_with_an_open_route_through_it_is_solvable() {
+        let wall = [
+            aabb((BOTTOM_END - 1.0, -0.5), (-2.0, 0.5)),
+            aabb((2.0, -0.5), (TOP_END + 1.0, 0.5)),
+        ];
+        let grid = PathGrid::from_aabbs(PATH_CELL_SIZE, wall.iter());
+
+        assert!(grid.is_solvable());
+        assert!(grid.is_reachable(left_goal(), right_goal()));
+        assert!(grid.find_path(left_goal(), right_goal()).is_some());
+    }
+
+    #[test]
+    fn an_unreachable_football_target_is_moved_somewhere_reachable() {
+        // a ring of corn with nothing inside it
+        let ring = [
+            aabb((3.5, 3.5), (4.0, 6.5)),
+            aabb((6.0, 3.5), (6.5, 6.5)),
+            aabb((3.5, 3.5), (6.5, 4.0)),
+            aabb((3.5, 6.0), (6.5, 6.5)),
+        ];
+        let grid = PathGrid::from_aabbs(PATH_CELL_SIZE, ring.iter());
+        let target = Vec3::new(5.0, 0.0, 5.0);
+
+        assert!(!grid.is_blocked(grid.cell(target).unwrap()));
+        assert!(!grid.is_reachable(left_goal(), target));
+
+        let repaired = grid.nearest_reachable(left_goal(), target).expect("most of the field is open");
+        assert_ne!(repaired, target);
+        assert!(grid.is_reachable(left_goal(), repaired));
+        assert!(repaired.distance(target) < 3.0);
+    }
+
+    #[test]
+    fn a_reachable_football_target_is_left_alone() {
+        let grid = PathGrid::new(PATH_CELL_SIZE);
+        let target = Vec3::new(5.0, 0.0, 5.0);
+        assert_eq!(grid.nearest_reachable(left_goal(), target), Some(target));
+    }
+
+    #[test]
+    fn refreshing_an_area_opens_up_corn_that_was_harvested() {
+        let stalk = aabb((0.0, 0.0), (0.8, 0.8));
+        let mut grid = PathGrid::from_aabbs(PATH_CELL_SIZE, [stalk].iter());
+        assert!(grid.is_blocked(grid.cell(Vec3::new(0.4, 0.0, 0.4)).unwrap()));
+
+        // the collision grid doesn't have it any more
+        grid.refresh_area(&stalk, &collision::CollisionGrid::default());
+        assert!(grid.blocked.iter().all(|blocked| !blocked));
+    }
+}
@@ -0,0 +1,317 @@
+use crate::{
+    asset_loading, assets::GameAssets, audio::GameAudio, cleanup, cutscene, game_controller, game_state,
+    ingame, ingame_ui, menus, options, player::{self, PlayerAction}, replay, title_screen::MenuAction, ui::text_size,
+    AppState, localization::{Localization, LocalizedText},
+};
+use bevy::prelude::*;
+use bevy::window::WindowFocused;
+use leafwing_input_manager::prelude::*;
+
+// Pause is pushed on top of InGame so the round underneath is left exactly as it was. Gameplay
+// only steps and on_update(InGame) systems only run while InGame is the current state, so the
+// combines, enemy flights, banter cooldown and death cooldown all hold still until it's popped.
+// Animations run on their own so they're paused here as well.
+
+pub struct PausePlugin;
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PauseMenu>()
+            .add_event::<PauseChoice>()
+            .add_system_set(
+                SystemSet::on_update(AppState::InGame)
+                    .with_system(pause_game.after(game_controller::store_controller_inputs))
+            )
+            .add_system_set(
+                SystemSet::on_enter(AppState::Pause)
+                    .with_system(reset_selection)
+                    .with_system(setup)
+                    .with_system(pause_animations),
+            )
+            // the options menu is pushed on top, the overlay comes back when it's popped
+            .add_system_set(
+                SystemSet::on_resume(AppState::Pause)
+                    .with_system(setup),
+            )
+            .add_system_set(
+                SystemSet::on_update(AppState::Pause)
+                    .with_system(update_menu_buttons)
+                    .with_system(handle_state_choices.after(update_menu_buttons))
+                    .with_system(leave_round.after(update_menu_buttons)),
+            )
+            .add_system_set(
+                SystemSet::on_pause(AppState::Pause)
+                    .with_system(cleanup::<CleanupMarker>)
+            )
+            .add_system_set(
+                SystemSet::on_exit(AppState::Pause)
+                    .with_system(cleanup::<CleanupMarker>)
+                    .with_system(resume_animations),
+            );
+    }
+}
+
+const CHOICES: [(PauseChoice, &str); 4] = [
+    (PauseChoice::Resume, "pause.resume"),
+    (PauseChoice::RestartRound, "pause.restart_round"),
+    (PauseChoice::Options, "pause.options"),
+    (PauseChoice::QuitToTitle, "pause.quit"),
+];
+
+#[derive(Component)]
+struct CleanupMarker;
+
+#[derive(Component)]
+struct PauseButton(usize);
+
+#[derive(Clone, Copy, PartialEq)]
+enum PauseChoice {
+    Resume,
+    RestartRound,
+    Options,
+    QuitToTitle,
+}
+
+#[derive(Default)]
+struct PauseMenu {
+    // what's on offer this time, a recording can't restart the round (see setup)
+    choices: Vec::<(PauseChoice, &'static str)>,
+    selected: usize,
+    // so the press that paused doesn't unpause straight away
+    input_cooldown: f32,
+    // only what was playing gets started again, anything a cutscene left stopped stays stopped
+    paused_animations: Vec::<Entity>,
+}
+
+fn pause_game(
+    mut app_state: ResMut<State<AppState>>,
+    mut focus_events: EventReader<WindowFocused>,
+    mut controller_events: EventReader<game_controller::ControllerEvent>,
+    players: Query<&ActionState<PlayerAction>, With<player::Player>>,
+    cutscene_state: Res<cutscene::CutsceneState>,
+) {
+    let focus_lost = focus_events.iter().any(|event| !event.focused);
+    let controller_lost = controller_events.iter().any(|event| matches!(event, game_controller::ControllerEvent::Disconnected(_)));
+    let pause_pressed = players.iter().any(|action_state| action_state.just_pressed(PlayerAction::Pause));
+
+    // a cutscene that's about to be pushed wins, it's already stopped gameplay
+    if (focus_lost || controller_lost || pause_pressed) && cutscene_state.current.is_none() {
+        app_state.push(AppState::Pause).unwrap();
+    }
+}
+
+// coming back from the options menu keeps Options picked
+fn reset_selection(mut pause_menu: ResMut<PauseMenu>) {
+    pause_menu.selected = 0;
+}
+
+fn pause_animations(
+    mut pause_menu: ResMut<PauseMenu>,
+    mut animations: Query<(Entity, &mut AnimationPlayer)>,
+) {
+    pause_menu.paused_animations.clear();
+    for (entity, mut animation) in &mut animations {
+        if !animation.is_paused() {
+            animation.pause();
+            pause_menu.paused_animations.push(entity);
+        }
+    }
+}
+
+fn resume_animations(
+    mut pause_menu: ResMut<PauseMenu>,
+    mut animations: Query<&mut AnimationPlayer>,
+) {
+    for entity in pause_menu.paused_animations.drain(..) {
+        if let Ok(mut animation) = animations.get_mut(entity) {
+            animation.resume();
+        }
+    }
+}
+
+fn setup(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    mut pause_menu: ResMut<PauseMenu>,
+    text_scaler: text_size::TextScaler,
+    localization: Res<Localization>,
+    recorder: Option<Res<replay::Recorder>>,
+) {
+    pause_menu.input_cooldown = 0.2;
+
+    // a replay starts from the first round with a fresh seed, a round started over partway
+    // through a run couldn't be played back from that so it isn't offered while recording
+    pause_menu.choices = CHOICES.iter()
+                                .filter(|(choice, _)| recorder.is_none() || *choice != PauseChoice::RestartRound)
+                                .cloned()
+                                .collect();
+    let choices = pause_menu.choices.clone();
+
+    commands
+        .spawn_bundle(InputManagerBundle {
+            input_map: MenuAction::default_input_map(),
+            action_state: ActionState::default(),
+        })
+        .insert(CleanupMarker);
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::ColumnReverse,
+                ..Default::default()
+            },
+            color: menus::OVERLAY_BACKGROUND.into(),
+            ..Default::default()
+        })
+        .insert(CleanupMarker)
+        .with_children(|parent| {
+            options::add_title(
+                parent,
+                localization.font(&game_assets),
+                text_scaler.scale(menus::DEFAULT_FONT_SIZE * 1.2),
+                &localization.get("pause.title"),
+                vec![LocalizedText("pause.title".to_string())],
+            );
+
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Percent(30.0), Val::Percent(40.0)),
+                        position_type: PositionType::Relative,
+                        justify_content: JustifyContent::Center,
+                        flex_direction: FlexDirection::ColumnReverse,
+                        margin: UiRect {
+                            left: Val::Auto,
+                            right: Val::Auto,
+                            top: Val::Percent(5.0),
+                            ..Default::default()
+                        },
+                        align_items: AlignItems::FlexStart,
+                        ..Default::default()
+                    },
+                    color: Color::NONE.into(),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    for (index, (_, key)) in choices.iter().enumerate() {
+                        parent
+                            .spawn_bundle(ButtonBundle {
+                                style: Style {
+                                    size: Size::new(Val::Percent(100.0), Val::Percent(22.0)),
+                                    margin: UiRect::all(Val::Auto),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    position_type: PositionType::Relative,
+                                    ..Default::default()
+                                },
+                                color: menus::NORMAL_BUTTON.into(),
+                                ..Default::default()
+                            })
+                            .insert(PauseButton(index))
+                            .with_children(|parent| {
+                                parent.spawn_bundle(TextBundle {
+                                    text: Text::from_section(
+                                        localization.get(key),
+                                        TextStyle {
+                                            font: localization.font(&game_assets),
+                                            font_size: text_scaler.scale(menus::BUTTON_LABEL_FONT_SIZE),
+                                            color: Color::WHITE,
+                                        }
+                                    ),
+                                    ..Default::default()
+                                })
+                                .insert(LocalizedText(key.to_string()));
+                            });
+                    }
+                });
+        });
+}
+
+fn update_menu_buttons(
+    mut pause_menu: ResMut<PauseMenu>,
+    mut buttons: Query<(&PauseButton, &mut UiColor)>,
+    action_state: Query<&ActionState<MenuAction>>,
+    players: Query<&ActionState<PlayerAction>, With<player::Player>>,
+    mut choice_event_writer: EventWriter<PauseChoice>,
+    game_assets: Res<GameAssets>,
+    mut audio: GameAudio,
+    time: Res<Time>,
+) {
+    pause_menu.input_cooldown -= time.delta_seconds();
+    pause_menu.input_cooldown = pause_menu.input_cooldown.clamp(-3.0, 3.0);
+    if pause_menu.input_cooldown > 0.0 { return; }
+
+    let action_state = match action_state.get_single() {
+        Ok(action_state) => action_state,
+        Err(_) => return,
+    };
+
+    if action_state.just_pressed(MenuAction::Up) {
+        audio.play_sfx(&game_assets.blip);
+        pause_menu.selected = pause_menu.selected.checked_sub(1).unwrap_or(pause_menu.choices.len() - 1);
+    }
+    if action_state.just_pressed(MenuAction::Down) {
+        audio.play_sfx(&game_assets.blip);
+        pause_menu.selected = (pause_menu.selected + 1) % pause_menu.choices.len();
+    }
+
+    for (button, mut color) in &mut buttons {
+        *color = if button.0 == pause_menu.selected { menus::HOVERED_BUTTON.into() } else { menus::NORMAL_BUTTON.into() };
+    }
+
+    // the same button that paused also unpauses
+    let unpause_pressed = players.iter().any(|action_state| action_state.just_pressed(PlayerAction::Pause));
+    if unpause_pressed {
+        choice_event_writer.send(PauseChoice::Resume);
+    } else if action_state.just_pressed(MenuAction::Select) {
+        audio.play_sfx(&game_assets.blip);
+        choice_event_writer.send(pause_menu.choices[pause_menu.selected].0);
+    }
+}
+
+// Resume and Options stay in the round so they only move the state stack
+fn handle_state_choices(
+    mut choice_event_reader: EventReader<PauseChoice>,
+    mut app_state: ResMut<State<AppState>>,
+) {
+    if let Some(choice) = choice_event_reader.iter().next() {
+        match choice {
+            PauseChoice::Resume => app_state.pop().unwrap(),
+            PauseChoice::Options => app_state.push(AppState::Options).unwrap(),
+            _ => (),
+        }
+    }
+}
+
+// InGame isn't exited when Pause is replaced so the round is cleaned up here,
+// the same as on the way out of a cutscene
+fn leave_round(
+    mut commands: Commands,
+    mut choice_event_reader: EventReader<PauseChoice>,
+    round_entities: Query<Entity, Or<(With<ingame::CleanupMarker>, With<ingame_ui::CleanupMarker>)>>,
+    mut assets_handler: asset_loading::AssetsHandler,
+    mut game_assets: ResMut<GameAssets>,
+    mut game_state: ResMut<game_state::GameState>,
+) {
+    let next_state = match choice_event_reader.iter().next() {
+        Some(PauseChoice::RestartRound) => {
+            game_state.score = 0;
+            game_state.player_scores = [0; 2];
+            game_state.corn_spawned = false;
+            AppState::ResetInGame
+        },
+        Some(PauseChoice::QuitToTitle) => {
+            *game_state = game_state::GameState::default();
+            AppState::TitleScreen
+        },
+        _ => return,
+    };
+
+    for entity in &round_entities {
+        commands.entity(entity).despawn_recursive();
+    }
+    assets_handler.load(next_state, &mut game_assets, &game_state);
+}
@@ -0,0 +1,77 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+// Small RON files that outlast a run, kept in the platform's config directory
+// (~/.config/football_combine on Linux). Browsers have nowhere to put them so on wasm
+// nothing is saved and loading always comes back empty.
+
+const DIRECTORY: &str = "football_combine";
+
+// None if the file isn't there or can't be read, either way the caller falls back to its default
+pub fn load<T: DeserializeOwned>(file_name: &str) -> Option<T> {
+    let contents = read(file_name)?;
+    match ron::de::from_str(&contents) {
+        Ok(value) => Some(value),
+        Err(error) => {
+            // kept to one side so the next save doesn't wipe out what someone might want to fix by hand
+            println!("couldn't read {}, moving it to {}.bad: {}", file_name, file_name, error);
+            set_aside(file_name);
+            None
+        }
+    }
+}
+
+pub fn save<T: Serialize>(file_name: &str, value: &T) {
+    match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => write(file_name, &contents),
+        Err(error) => println!("couldn't save {}: {}", file_name, error),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn path(file_name: &str) -> Option<std::path::PathBuf> {
+    dirs::config_dir().map(|directory| directory.join(DIRECTORY).join(file_name))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read(file_name: &str) -> Option<String> {
+    std::fs::read_to_string(path(file_name)?).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write(file_name: &str, contents: &str) {
+    let path = match path(file_name) {
+        Some(path) => path,
+        None => return,
+    };
+
+    // written next to the real file first so quitting halfway through can't leave half a file behind
+    let temporary = path.with_extension("tmp");
+    let result = path.parent()
+                     .map_or(Ok(()), std::fs::create_dir_all)
+                     .and_then(|_| std::fs::write(&temporary, contents))
+                     .and_then(|_| std::fs::rename(&temporary, &path));
+
+    if let Err(error) = result {
+        println!("couldn't save {}: {}", path.display(), error);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn set_aside(file_name: &str) {
+    if let Some(path) = path(file_name) {
+        let _ = std::fs::rename(&path, path.with_file_name(format!("{}.bad", file_name)));
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read(_file_name: &str) -> Option<String> {
+    None
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write(_file_name: &str, _contents: &str) {
+}
+
+#[cfg(target_arch = "wasm32")]
+fn set_aside(_file_name: &str) {
+}
@@ -1,17 +1,20 @@
-use crate::{AppState, game_controller, direction, game_state, collision, assets::GameAssets, component_adder::AnimationLink, ZeroSignum, LEFT_GOAL, RIGHT_GOAL, football, ingame, billboard::Billboard, cutscene, audio::GameAudio};
+use crate::{AppState, controls, game_controller, direction, game_state, collision, assets::GameAssets, component_adder::AnimationLink, LEFT_GOAL, RIGHT_GOAL, football, ingame, billboard::Billboard, cutscene, audio::GameAudio, timestep::{self, FixedEventAppExt}};
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 pub struct PlayerPlugin;
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugin(InputManagerPlugin::<PlayerAction>::default())
-            .add_event::<PlayerMoveEvent>()
-            .add_event::<PlayerBladeEvent>()
-            .add_system_set(
-                SystemSet::on_update(AppState::InGame)
+            .add_fixed_event::<PlayerMoveEvent>()
+            .add_fixed_event::<PlayerBladeEvent>()
+            .add_fixed_event::<RunnerRespawnEvent>()
+            .add_system_set_to_stage(
+                timestep::FixedUpdateStage,
+                timestep::gameplay()
                     .with_system(handle_controllers.before(handle_input))
                     .with_system(handle_input)
                     .with_system(handle_player_blade_event)
@@ -25,6 +28,26 @@ pub struct PlayerBladeEvent {
     pub entity: Entity,
 }
 
+// a runner who went down in versus is back at the start, anything still holding on lets go
+pub struct RunnerRespawnEvent {
+    pub entity: Entity,
+}
+
+// runners stand this far apart across the field in versus
+const VERSUS_SPACING: f32 = 4.0;
+
+// where a runner starts, and in versus where they come back after going down
+pub fn starting_position(number: usize, game_state: &game_state::GameState) -> Vec3 {
+    let x = if game_state.versus {
+        (number as f32 - 0.5) * VERSUS_SPACING
+    } else {
+        0.0
+    };
+    let z = if game_state.touchdown_on_leftside { RIGHT_GOAL } else { LEFT_GOAL };
+
+    Vec3::new(x, 0.0, z)
+}
+
 pub fn handle_player_blade_event(
     mut commands: Commands,
     mut player_blade_event_reader: EventReader<PlayerBladeEvent>,
@@ -74,7 +97,7 @@ pub fn check_for_touchdown(
         && ((game_state.touchdown_on_leftside && player_transform.translation.z <= LEFT_GOAL) 
          || (!game_state.touchdown_on_leftside && player_transform.translation.z >= RIGHT_GOAL)) {
             player.has_football = false;
-            touchdown_event_writer.send(game_state::TouchdownEvent);
+            touchdown_event_writer.send(game_state::TouchdownEvent { player: player.number });
             audio.play_sfx(&game_assets.touch_down);
             println!("Sending touch");
             for (_, mut visibility, parent) in &mut carried_footballs {
@@ -87,8 +110,8 @@ pub fn check_for_touchdown(
 }
 
 pub fn move_player(
-    time: Res<Time>,
-    mut players: Query<(Entity, &mut Transform, &mut Player, &AnimationLink)>,
+    time: Res<timestep::FixedTime>,
+    mut players: Query<(Entity, &mut Transform, &mut Player, &AnimationLink, &mut timestep::Interpolated)>,
     mut animations: Query<&mut AnimationPlayer>,
     mut player_move_event_reader: EventReader<PlayerMoveEvent>,
     collidables: collision::Collidables,
@@ -96,14 +119,17 @@ pub fn move_player(
     game_assets: ResMut<GameAssets>,
     mut audio: GameAudio,
     mut cutscene_state: ResMut<cutscene::CutsceneState>,
+    mut carried_footballs: Query<(&mut Visibility, &Parent), With<football::CarriedFootball>>,
+    mut respawn_event_writer: EventWriter<RunnerRespawnEvent>,
+    mut football_launch_event_writer: EventWriter<football::LaunchFootballEvent>,
 ) {
     let mut move_events = HashMap::new();
     for move_event in player_move_event_reader.iter() {
         move_events.entry(move_event.entity).or_insert(move_event);
     }
 
-    for (entity, mut transform, mut player, animation_link) in players.iter_mut() {
-        if !player.is_tackled && game_state.attached_enemies >= 3 {
+    for (entity, mut transform, mut player, animation_link, mut interpolated) in players.iter_mut() {
+        if !player.is_tackled && player.attached_strength >= 3.0 {
             if let Some(animation_entity) = animation_link.entity {
                 let mut animation = animations.get_mut(animation_entity).unwrap();
                 animation.play(game_assets.person_dive.clone_weak());
@@ -119,12 +145,39 @@ pub fn move_player(
             player.dead_cooldown = player.dead_cooldown.clamp(-3.0, 30.0);
 
             if player.dead_cooldown <= 0.0 { 
+                // there's still someone else out there so it's straight back to the start
+                if game_state.versus {
+                    // still counted for the results, there's just no death cutscene
+                    if player.is_dead {
+                        game_state.death_count += 1;
+                    }
+                    if player.has_football {
+                        player.has_football = false;
+                        for (mut visibility, parent) in &mut carried_footballs {
+                            if parent.get() == entity {
+                                visibility.is_visible = false;
+                            }
+                        }
+                        football_launch_event_writer.send(football::LaunchFootballEvent);
+                    }
+
+                    player.is_dead = false;
+                    player.is_tackled = false;
+                    player.velocity = Vec3::ZERO;
+                    player.attached_enemies = 0;
+                    player.attached_strength = 0.0;
+                    transform.translation = starting_position(player.number, &game_state);
+                    interpolated.teleport(*transform);
+                    respawn_event_writer.send(RunnerRespawnEvent { entity });
+                    continue;
+                }
+
                 if player.is_dead {
                     game_state.death_count += 1;
                     player.is_dead = false;
-                    cutscene_state.init(cutscene::Cutscene::Death);
+                    cutscene_state.init(cutscene::Cutscene::death(game_state.death_count));
                 } else if player.is_tackled {
-                    cutscene_state.init(cutscene::Cutscene::Tackle);
+                    cutscene_state.init(cutscene::Cutscene::new("tackle"));
                 }
                 return; 
             }
@@ -138,12 +191,13 @@ pub fn move_player(
         player.velocity *= friction.powf(time.delta_seconds());
         if let Some(move_event) = move_events.get(&entity) {
             match move_event.movement {
-                Movement::Normal(direction) => {
-                    let acceleration = Vec3::from(direction);
+                Movement::Normal(amount) => {
+                    // x is along the field like Direction::UP, y across it like Direction::RIGHT
+                    let acceleration = Vec3::new(amount.x, 0.0, amount.y).clamp_length_max(1.0);
                     let speed = speed -
                                // slow down player for each enemy attached
-                                ((speed * 0.1) * game_state.attached_enemies as f32);
-                    player.velocity += (acceleration.zero_signum() * speed) * time.delta_seconds();
+                                ((speed * 0.1) * player.attached_strength);
+                    player.velocity += (acceleration * speed) * time.delta_seconds();
                 }
             }
         }
@@ -154,7 +208,6 @@ pub fn move_player(
             &transform.translation,
             &mut new_translation,
             &mut player.velocity,
-            &time
         );
 
         let angle = (-(new_translation.z - transform.translation.z))
@@ -196,7 +249,7 @@ pub fn move_player(
 }
 
 
-#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug)]
+#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Serialize, Deserialize)]
 pub enum PlayerAction {
     Up,
     Down,
@@ -207,6 +260,9 @@ pub enum PlayerAction {
     ActionDown,
     ActionLeft,
     ActionRight,
+
+    // read by pause.rs every frame rather than by gameplay
+    Pause,
 }
 
 impl PlayerAction {
@@ -236,6 +292,8 @@ pub struct PlayerMoveEvent {
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
 pub struct Player {
+    // 0 for the first runner, 1 for the second in versus
+    pub number: usize,
     pub velocity: Vec3,
     pub speed: f32,
     pub rotation_speed: f32,
@@ -246,13 +304,17 @@ pub struct Player {
     pub random: f32,
     pub current_animation: Handle<AnimationClip>,
     pub has_football: bool,
+    pub attached_enemies: usize,
+    // how hard the attached enemies are holding on, the player is tackled at 3.0
+    pub attached_strength: f32,
+    // how far the stick is pushed, zero when it isn't so the keys and dpad are used instead
+    pub stick: Vec2,
 }
 
 impl Player {
-    pub fn new() -> Self {
-        let mut rng = rand::thread_rng();
-
+    pub fn new(number: usize, rng: &mut impl Rng) -> Self {
         Player {
+            number,
             velocity: Vec3::default(),
             speed: 40.0,
             rotation_speed: 1.0,
@@ -263,6 +325,9 @@ impl Player {
             random: rng.gen_range(0.5..1.0),
             current_animation: Handle::<AnimationClip>::default(),
             has_football: false,
+            attached_enemies: 0,
+            attached_strength: 0.0,
+            stick: Vec2::ZERO,
         }
     }
 }
@@ -270,14 +335,16 @@ impl Player {
 #[derive(Bundle)]
 pub struct PlayerBundle {
     player: Player,
+    interpolated: timestep::Interpolated,
     #[bundle]
     input_manager: InputManagerBundle<PlayerAction>,
 }
 
 impl PlayerBundle {
-    pub fn new() -> Self {
+    pub fn new(number: usize, rng: &mut impl Rng) -> Self {
         PlayerBundle {
-            player: Player::new(),
+            player: Player::new(number, rng),
+            interpolated: timestep::Interpolated::default(),
             input_manager: InputManagerBundle {
                 input_map: PlayerBundle::default_input_map(),
                 action_state: ActionState::default(),
@@ -285,124 +352,29 @@ impl PlayerBundle {
         }
     }
 
+    // the saved bindings are swapped in by controls::apply_controls once it's spawned
     fn default_input_map() -> InputMap<PlayerAction> {
-        use PlayerAction::*;
-        let mut input_map = InputMap::default();
-
-        input_map.set_gamepad(Gamepad { id: 0 });
-
-        // Movement
-        input_map.insert(KeyCode::Up, Up);
-        input_map.insert(KeyCode::W, Up);
-        input_map.insert(KeyCode::Z, Up);
-        input_map.insert(GamepadButtonType::DPadUp, Up);
-
-        input_map.insert(KeyCode::Down, Down);
-        input_map.insert(KeyCode::S, Down);
-        input_map.insert(GamepadButtonType::DPadDown, Down);
-
-        input_map.insert(KeyCode::Left, Left);
-        input_map.insert(KeyCode::A, Left);
-        input_map.insert(KeyCode::Q, Left);
-        input_map.insert(GamepadButtonType::DPadLeft, Left);
-
-        input_map.insert(KeyCode::Right, Right);
-        input_map.insert(KeyCode::D, Right);
-        input_map.insert(GamepadButtonType::DPadRight, Right);
-
-        // Actions
-        input_map.insert(KeyCode::I, ActionUp);
-        input_map.insert(GamepadButtonType::North, ActionUp);
-
-        input_map.insert(KeyCode::K, ActionDown);
-        input_map.insert(GamepadButtonType::South, ActionDown);
-
-        input_map.insert(KeyCode::J, ActionLeft);
-        input_map.insert(GamepadButtonType::West, ActionLeft);
-
-        input_map.insert(KeyCode::L, ActionRight);
-        input_map.insert(GamepadButtonType::East, ActionRight);
-
-        input_map
+        controls::Controls::default().player_input_map(None)
     }
 }
 
 
-fn handle_controllers(
+// buttons come through the input map, only the stick needs carrying over
+pub fn handle_controllers(
     controllers: Res<game_controller::GameController>,
-    game_state: Res<game_state::GameState>,
-    mut players: Query<(Entity, &mut ActionState<PlayerAction>), With<Player>>,
+    mut players: Query<&mut Player>,
 ) {
-    for (_, mut action_state) in players.iter_mut() {
-        for (_, pressed) in controllers.pressed.iter() {
-            // release all buttons
-            // this probably affects durations but for
-            // this game it might not be a big deal
-            action_state.release(PlayerAction::Left);
-            action_state.release(PlayerAction::Right);
-            action_state.release(PlayerAction::Up);
-            action_state.release(PlayerAction::Down);
-
-            if pressed.contains(&game_controller::GameButton::Left) {
-                action_state.press(PlayerAction::Left);
-            }
-            if pressed.contains(&game_controller::GameButton::Right) {
-                action_state.press(PlayerAction::Right);
-            }
-            if pressed.contains(&game_controller::GameButton::Up) {
-                action_state.press(PlayerAction::Up);
-            }
-            if pressed.contains(&game_controller::GameButton::Down) {
-                action_state.press(PlayerAction::Down);
-            }
-            if pressed.contains(&game_controller::GameButton::ActionDown) {
-                action_state.press(PlayerAction::ActionDown);
-            } else {
-                action_state.release(PlayerAction::ActionDown);
-            }
-            if pressed.contains(&game_controller::GameButton::ActionUp) {
-                action_state.press(PlayerAction::ActionUp);
-            } else {
-                action_state.release(PlayerAction::ActionUp);
-            }
-            if pressed.contains(&game_controller::GameButton::ActionLeft) {
-                action_state.press(PlayerAction::ActionLeft);
-            } else {
-                action_state.release(PlayerAction::ActionLeft);
-            }
-            if pressed.contains(&game_controller::GameButton::ActionRight) {
-                action_state.press(PlayerAction::ActionRight);
-            } else {
-                action_state.release(PlayerAction::ActionRight);
-            }
-        }
-
-        for (_, just_pressed) in controllers.just_pressed.iter() {
-            if just_pressed.contains(&game_controller::GameButton::ActionUp) {
-                action_state.release(PlayerAction::ActionUp);
-                action_state.press(PlayerAction::ActionUp);
-            }
-            if just_pressed.contains(&game_controller::GameButton::ActionDown) {
-                action_state.release(PlayerAction::ActionDown);
-                action_state.press(PlayerAction::ActionDown);
-            }
-            if just_pressed.contains(&game_controller::GameButton::ActionRight) {
-                action_state.release(PlayerAction::ActionRight);
-                action_state.press(PlayerAction::ActionRight);
-            }
-            if just_pressed.contains(&game_controller::GameButton::ActionLeft) {
-                action_state.release(PlayerAction::ActionLeft);
-                action_state.press(PlayerAction::ActionLeft);
-            }
-        }
+    for mut player in players.iter_mut() {
+        player.stick = if player.number == 0 { controllers.stick } else { controllers.second_stick };
     }
 }
 
 pub enum Movement {
-    Normal(direction::Direction),
+    // up to 1.0 long, less when the stick is only pushed part way
+    Normal(Vec2),
 }
 
-fn handle_input(
+pub fn handle_input(
     mut app_state: ResMut<State<AppState>>,
     players: Query<(Entity, &ActionState<PlayerAction>, &Transform, &Player)>,
     game_state: Res<game_state::GameState>,
@@ -418,10 +390,18 @@ fn handle_input(
             }
         }
 
-        if direction != direction::Direction::NEUTRAL {
+        // the stick is turned into directions too, its own amount wins while it's pushed.
+        // Direction is always unit length so diagonals on the keys are as fast as straight lines
+        let amount = if player.stick != Vec2::ZERO {
+            Vec2::new(player.stick.y, player.stick.x)
+        } else {
+            Vec2::from(direction)
+        };
+
+        if amount != Vec2::ZERO {
             player_move_event_writer.send(PlayerMoveEvent {
                 entity,
-                movement: Movement::Normal(direction),
+                movement: Movement::Normal(amount),
             });
         }
 
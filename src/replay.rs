@@ -0,0 +1,283 @@
+use crate::{
+    AppState, asset_loading, assets::GameAssets, cutscene, game_state, player::{self, PlayerAction}, rng,
+    timestep, title_screen::MenuAction,
+};
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+// "--record <file>" writes what was pressed on every gameplay step along with the seed,
+// "--replay <file>" plays that back so a run can be reproduced exactly. Gameplay runs at a
+// fixed rate (see timestep.rs) so steps are recorded rather than frames and their deltas.
+// Menus and cutscenes aren't recorded, they're skipped through on playback.
+
+pub struct ReplayPlugin;
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        match ReplayMode::from_args() {
+            ReplayMode::Off => (),
+            ReplayMode::Recording(path) => {
+                app.insert_resource(Recorder {
+                        path,
+                        run: 0,
+                        replay: Replay::default(),
+                    })
+                    .add_system_set_to_stage(
+                        timestep::FixedUpdateStage,
+                        timestep::gameplay()
+                            .with_system(record_step.after(player::handle_controllers).before(player::handle_input))
+                    )
+                    .add_system_set(
+                        // every death and round end goes through a cutscene
+                        SystemSet::on_enter(AppState::Cutscene)
+                            .with_system(save_recording)
+                    );
+            },
+            ReplayMode::Playing(path) => {
+                let replay = match Replay::load(&path) {
+                    Ok(replay) => replay,
+                    Err(error) => {
+                        // nothing's been started yet so there's nothing to shut down
+                        println!("couldn't load replay from {:?}: {}", path, error);
+                        std::process::exit(1);
+                    },
+                };
+                println!("replaying {} steps from {:?} with seed {}", replay.steps.len(), path, replay.seed);
+
+                // the seed recorded with the replay wins over --seed
+                app.insert_resource(rng::GameRng::fixed(replay.seed))
+                    .insert_resource(Playback {
+                        replay,
+                        index: 0,
+                        started: false,
+                        finished: false,
+                    })
+                    .add_system_set_to_stage(
+                        timestep::FixedUpdateStage,
+                        timestep::gameplay()
+                            .with_system(press_recorded_actions.after(player::handle_controllers).before(player::handle_input))
+                    )
+                    .add_system_set(
+                        SystemSet::on_update(AppState::Cutscene)
+                            .with_system(skip_cutscene_text.before(cutscene::handle_input))
+                    )
+                    .add_system_set(
+                        SystemSet::on_update(AppState::TitleScreen)
+                            .with_system(start_playback)
+                    )
+                    .add_system_set(
+                        SystemSet::on_update(AppState::HighScores)
+                            .with_system(finish_at_high_scores)
+                    );
+            },
+        }
+    }
+}
+
+pub enum ReplayMode {
+    Off,
+    Recording(PathBuf),
+    Playing(PathBuf),
+}
+
+impl ReplayMode {
+    pub fn from_args() -> Self {
+        let mut args = std::env::args();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--record" => if let Some(path) = args.next() {
+                    return ReplayMode::Recording(PathBuf::from(path));
+                },
+                "--replay" => if let Some(path) = args.next() {
+                    return ReplayMode::Playing(PathBuf::from(path));
+                },
+                _ => ()
+            }
+        }
+
+        ReplayMode::Off
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct Replay {
+    pub seed: u64,
+    pub graphics_high: bool,
+    pub shadows_on: bool,
+    pub is_latest: bool,
+    // what the run ended with so playback can check it got the same result
+    pub score: usize,
+    pub death_count: usize,
+    pub steps: Vec::<ReplayStep>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ReplayStep {
+    pub pressed: Vec::<PlayerAction>,
+    // older replays were made before the stick was analog
+    #[serde(default)]
+    pub stick: Vec2,
+}
+
+impl Replay {
+    fn load(path: &PathBuf) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        Ok(ron::from_str(&contents)?)
+    }
+
+    fn save(&self, path: &PathBuf) {
+        match ron::to_string(self) {
+            Ok(contents) => if let Err(e) = std::fs::write(path, contents) {
+                println!("couldn't write replay to {:?}: {}", path, e);
+            },
+            Err(e) => println!("couldn't serialize replay: {}", e),
+        }
+    }
+}
+
+// only there while recording
+pub struct Recorder {
+    path: PathBuf,
+    run: u32,
+    replay: Replay,
+}
+
+fn record_step(
+    mut recorder: ResMut<Recorder>,
+    game_rng: Res<rng::GameRng>,
+    game_state: Res<game_state::GameState>,
+    players: Query<(&ActionState<PlayerAction>, &player::Player)>,
+) {
+    // replays only know about one runner
+    if game_state.versus { return; }
+
+    // starting a new game from the menu starts a new recording
+    if recorder.run != game_rng.run() {
+        recorder.run = game_rng.run();
+        recorder.replay = Replay {
+            seed: game_rng.seed(),
+            graphics_high: game_state.graphics_high,
+            shadows_on: game_state.shadows_on,
+            is_latest: game_state.is_latest,
+            ..default()
+        };
+    }
+
+    let stick = players.get_single()
+                       .map(|(_, player)| player.stick)
+                       .unwrap_or(Vec2::ZERO);
+    let pressed = players.get_single()
+                         .map(|(action_state, _)| PlayerAction::variants()
+                                                 // pausing isn't part of the run, playback would stop on it
+                                                 .filter(|action| *action != PlayerAction::Pause)
+                                                 .filter(|action| action_state.pressed(*action))
+                                                 .collect())
+                         .unwrap_or_default();
+    recorder.replay.steps.push(ReplayStep {
+        pressed,
+        stick,
+    });
+}
+
+fn save_recording(
+    mut recorder: ResMut<Recorder>,
+    game_state: Res<game_state::GameState>,
+) {
+    if recorder.replay.steps.is_empty() { return; }
+
+    recorder.replay.score = game_state.score;
+    recorder.replay.death_count = game_state.death_count;
+    recorder.replay.save(&recorder.path);
+}
+
+struct Playback {
+    replay: Replay,
+    index: usize,
+    started: bool,
+    finished: bool,
+}
+
+impl Playback {
+    fn is_finished(&self) -> bool {
+        self.index >= self.replay.steps.len()
+    }
+
+    fn finish(&mut self, game_state: &game_state::GameState, exit: &mut EventWriter<AppExit>) {
+        if self.finished { return; }
+        self.finished = true;
+
+        let matches = game_state.score == self.replay.score && game_state.death_count == self.replay.death_count;
+        println!("replay finished: score {} (recorded {}) deaths {} (recorded {}) {}",
+                 game_state.score, self.replay.score,
+                 game_state.death_count, self.replay.death_count,
+                 if matches { "OK" } else { "MISMATCH" });
+        exit.send(AppExit);
+    }
+}
+
+fn press_recorded_actions(
+    mut playback: ResMut<Playback>,
+    game_state: Res<game_state::GameState>,
+    mut players: Query<(&mut ActionState<PlayerAction>, &mut player::Player)>,
+    mut exit: EventWriter<AppExit>,
+) {
+    if playback.is_finished() {
+        playback.finish(&game_state, &mut exit);
+        return;
+    }
+
+    let step = &playback.replay.steps[playback.index];
+    for (mut action_state, mut player) in &mut players {
+        action_state.release_all();
+        for action in step.pressed.iter() {
+            action_state.press(*action);
+        }
+        player.stick = step.stick;
+    }
+    playback.index += 1;
+}
+
+fn skip_cutscene_text(
+    mut action_states: Query<&mut ActionState<MenuAction>>,
+) {
+    for mut action_state in &mut action_states {
+        action_state.press(MenuAction::Select);
+    }
+}
+
+// a run that gets to the end stops at the high score table, before any initials are asked for
+fn finish_at_high_scores(
+    mut playback: ResMut<Playback>,
+    game_state: Res<game_state::GameState>,
+    mut exit: EventWriter<AppExit>,
+) {
+    if playback.is_finished() {
+        playback.finish(&game_state, &mut exit);
+    }
+}
+
+fn start_playback(
+    mut playback: ResMut<Playback>,
+    mut game_state: ResMut<game_state::GameState>,
+    mut game_rng: ResMut<rng::GameRng>,
+    mut game_assets: ResMut<GameAssets>,
+    mut assets_handler: asset_loading::AssetsHandler,
+    mut exit: EventWriter<AppExit>,
+) {
+    if playback.started {
+        // the run ended back on the title screen
+        if playback.is_finished() {
+            playback.finish(&game_state, &mut exit);
+        }
+        return;
+    }
+
+    playback.started = true;
+    *game_state = game_state::GameState::initialize(playback.replay.graphics_high,
+                                                    playback.replay.shadows_on,
+                                                    playback.replay.is_latest);
+    game_rng.start_run();
+    assets_handler.load(AppState::InGame, &mut game_assets, &mut game_state);
+}
@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
+use std::collections::HashMap;
+
+pub struct RngPlugin;
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(match seed_from_args() {
+            Some(seed) => GameRng::fixed(seed),
+            None => GameRng::new(rand::random()),
+        });
+    }
+}
+
+// Each subsystem draws from its own stream so that, for example, extra banter rolls
+// don't shift where the next football lands.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum RngStream {
+    Football,
+    Enemy,
+    Combine,
+    Corn,
+    Banter,
+    Player,
+    Maze,
+    Commentary,
+}
+
+impl RngStream {
+    fn salt(self) -> u64 {
+        (self as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }
+}
+
+pub struct GameRng {
+    seed: u64,
+    is_fixed: bool,
+    run: u32,
+    streams: HashMap<RngStream, StdRng>,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        GameRng {
+            seed,
+            is_fixed: false,
+            run: 0,
+            streams: HashMap::new(),
+        }
+    }
+
+    // a seed that was asked for explicitly is kept for every run of the session
+    pub fn fixed(seed: u64) -> Self {
+        GameRng {
+            is_fixed: true,
+            ..GameRng::new(seed)
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    // counts how many games have been started this session
+    pub fn run(&self) -> u32 {
+        self.run
+    }
+
+    pub fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.streams.clear();
+    }
+
+    // called when a new game starts so the run can be reproduced from its seed alone
+    pub fn start_run(&mut self) {
+        if self.is_fixed {
+            self.streams.clear();
+        } else {
+            self.reseed(rand::random());
+        }
+        self.run += 1;
+        println!("rng seed: {}", self.seed);
+    }
+
+    pub fn stream(&mut self, stream: RngStream) -> &mut StdRng {
+        let seed = self.seed;
+        self.streams
+            .entry(stream)
+            .or_insert_with(|| StdRng::seed_from_u64(seed ^ stream.salt()))
+    }
+}
+
+// reads "--seed <number>" from the command line
+fn seed_from_args() -> Option<u64> {
+    let mut args = std::env::args();
+    while let Some(arg) = args.next() {
+        if arg == "--seed" {
+            return args.next().and_then(|seed| seed.parse().ok());
+        }
+    }
+
+    None
+}
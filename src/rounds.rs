@@ -0,0 +1,174 @@
+use crate::{assets::GameAssets, cutscene::Cutscene, harvest_strategy::HarvestPattern};
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use serde::Deserialize;
+
+// Everything that changes from one round to the next comes from assets/data/game.rounds.ron,
+// adding a round is a matter of adding an entry there. Until the file is loaded (or in headless
+// runs where it never is) the built in default is the original three rounds. Another set of
+// rounds can be played with "--rounds <file>", data/harvest_patterns.rounds.ron has the other
+// combine patterns and a second combine from round two on.
+
+const ROUNDS_FILE: &str = "data/game.rounds.ron";
+
+pub struct RoundsPlugin;
+impl Plugin for RoundsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<RoundDefinitions>()
+            .init_asset_loader::<RoundDefinitionsLoader>()
+            .init_resource::<Rounds>()
+            // assets are added before PreUpdate so the rounds are up to date by the time
+            // the state that was waiting on them is entered
+            .add_system_to_stage(CoreStage::PreUpdate, update_rounds);
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, TypeUuid)]
+#[uuid = "b3a4e2c1-6f0d-4c57-8d1e-2a9f7c3e5b40"]
+pub struct RoundDefinitions {
+    pub rounds: Vec::<RoundDefinition>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RoundDefinition {
+    pub name: String,
+    // scenery glb, any nodes named maze in it are filled with corn unless generate_corn is set
+    pub maze_model: String,
+    // carve the corn with maze_generator instead of filling the maze nodes, the shipped rounds all do
+    #[serde(default)]
+    pub generate_corn: bool,
+    pub lighting: LightingPreset,
+    // which enemy archetypes to spawn and how many
+    pub enemies: Vec::<(String, usize)>,
+    pub combines: Vec::<CombineDefinition>,
+    // names of scripts in game.cutscenes.ron
+    pub intro: Cutscene,
+    pub outro: Cutscene,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct CombineDefinition {
+    pub pattern: HarvestPattern,
+    // slowed down a bit when not playing the latest version
+    pub speed: f32,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+pub enum LightingPreset {
+    Day,
+    Sunset,
+    Night,
+}
+
+pub struct Rounds {
+    definitions: Vec::<RoundDefinition>,
+}
+
+impl Default for Rounds {
+    fn default() -> Self {
+        let combine = |pattern| CombineDefinition { pattern, speed: 40.0 };
+        let enemies = |count| vec![("enemy".to_string(), count)];
+
+        Rounds {
+            definitions: vec![
+                RoundDefinition {
+                    name: "Round One".to_string(),
+                    maze_model: "models/maze.glb".to_string(),
+                    generate_corn: true,
+                    lighting: LightingPreset::Day,
+                    enemies: enemies(3),
+                    combines: vec![combine(HarvestPattern::Random)],
+                    intro: Cutscene::new("intro"),
+                    outro: Cutscene::new("round_one_over"),
+                },
+                RoundDefinition {
+                    name: "Round Two".to_string(),
+                    maze_model: "models/maze_01.glb".to_string(),
+                    generate_corn: true,
+                    lighting: LightingPreset::Sunset,
+                    enemies: enemies(5),
+                    combines: vec![combine(HarvestPattern::Random)],
+                    intro: Cutscene::new("level_two_intro"),
+                    outro: Cutscene::new("round_two_over"),
+                },
+                RoundDefinition {
+                    name: "Round Three".to_string(),
+                    maze_model: "models/maze_01.glb".to_string(),
+                    generate_corn: true,
+                    lighting: LightingPreset::Night,
+                    enemies: enemies(6),
+                    combines: vec![combine(HarvestPattern::Random)],
+                    intro: Cutscene::new("level_three_intro"),
+                    outro: Cutscene::new("round_three_over"),
+                },
+            ],
+        }
+    }
+}
+
+// reads "--rounds <file>" from the command line, the path is under assets like the default
+pub fn rounds_file() -> String {
+    let mut args = std::env::args();
+    while let Some(arg) = args.next() {
+        if arg == "--rounds" {
+            if let Some(file) = args.next() {
+                return file;
+            }
+        }
+    }
+
+    ROUNDS_FILE.to_string()
+}
+
+impl Rounds {
+    // rounds past the end of the list repeat the last one
+    pub fn get(&self, round: usize) -> &RoundDefinition {
+        self.definitions
+            .get(round)
+            .or(self.definitions.last())
+            .expect("there should always be at least one round")
+    }
+
+    pub fn is_last(&self, round: usize) -> bool {
+        round + 1 >= self.definitions.len()
+    }
+}
+
+fn update_rounds(
+    mut rounds: ResMut<Rounds>,
+    definitions: Res<Assets<RoundDefinitions>>,
+    game_assets: Res<GameAssets>,
+) {
+    if !definitions.is_changed() { return; }
+
+    if let Some(definitions) = definitions.get(&game_assets.rounds) {
+        if definitions.rounds.is_empty() {
+            println!("rounds file has no rounds, keeping the ones already loaded");
+            return;
+        }
+
+        rounds.definitions = definitions.rounds.clone();
+    }
+}
+
+#[derive(Default)]
+pub struct RoundDefinitionsLoader;
+
+impl AssetLoader for RoundDefinitionsLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let definitions: RoundDefinitions = ron::de::from_bytes(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(definitions));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["rounds.ron"]
+    }
+}
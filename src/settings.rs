@@ -0,0 +1,114 @@
+use crate::persistence;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// What the player picked in the options menu along with volumes and the window mode, kept in
+// settings.ron between runs and applied in bootstrap before the splash screen. The file has a
+// version: fields that are only added pick up their defaults when an older file is read, a change
+// to what an existing field means gets a step in migrate. Anything unreadable is set aside and
+// the defaults are used instead.
+
+const SETTINGS_FILE: &str = "settings.ron";
+pub const SETTINGS_VERSION: u32 = 1;
+
+pub struct SettingsPlugin;
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Settings::load());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Settings {
+    // a file without one predates versioning and goes through migrate like any older file
+    #[serde(default = "unversioned")]
+    pub version: u32,
+    pub graphics_high: bool,
+    pub shadows_on: bool,
+    // the latest version of the game instead of the jam one
+    pub is_latest: bool,
+    pub locale: String,
+    pub music_volume: f32,
+    pub sound_volume: f32,
+    pub talk_volume: f32,
+    pub fullscreen: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            version: SETTINGS_VERSION,
+            graphics_high: true,
+            shadows_on: true,
+            is_latest: false,
+            locale: "en".to_string(),
+            music_volume: 0.5,
+            sound_volume: 0.2,
+            talk_volume: 0.2,
+            fullscreen: true,
+        }
+    }
+}
+
+fn unversioned() -> u32 {
+    0
+}
+
+impl Settings {
+    fn load() -> Self {
+        persistence::load::<Settings>(SETTINGS_FILE)
+            .map(migrate)
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        persistence::save(SETTINGS_FILE, self);
+    }
+}
+
+fn migrate(settings: Settings) -> Settings {
+    let mut settings = match settings.version {
+        SETTINGS_VERSION => settings,
+        version if version > SETTINGS_VERSION => {
+            println!("settings are from a newer version ({}), using the defaults", version);
+            Settings::default()
+        },
+        // version 0 is the same format without the version written down
+        0 => Settings { version: SETTINGS_VERSION, ..settings },
+        // when the format changes the step up from each older version goes here
+        version => {
+            println!("don't know how to update settings from version {}, using the defaults", version);
+            Settings::default()
+        },
+    };
+
+    // hand edited files can have anything in them
+    settings.music_volume = settings.music_volume.clamp(0.0, 1.0);
+    settings.sound_volume = settings.sound_volume.clamp(0.0, 1.0);
+    settings.talk_volume = settings.talk_volume.clamp(0.0, 1.0);
+    settings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_file_without_a_version_is_migrated_not_taken_as_current() {
+        let settings: Settings = ron::from_str("(graphics_high: false, music_volume: 0.3)").unwrap();
+        assert_eq!(settings.version, 0);
+
+        let settings = migrate(settings);
+        assert_eq!(settings.version, SETTINGS_VERSION);
+        assert!(!settings.graphics_high);
+        assert_eq!(settings.music_volume, 0.3);
+        assert_eq!(settings.sound_volume, Settings::default().sound_volume);
+    }
+
+    #[test]
+    fn a_newer_file_falls_back_to_the_defaults() {
+        let settings: Settings = ron::from_str("(version: 99, graphics_high: false)").unwrap();
+        assert!(migrate(settings).graphics_high);
+    }
+}
@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use crate::{AppState, ui::text_size, assets::GameAssets, menus, cleanup, asset_loading, game_state};
+use crate::{AppState, ui::text_size, assets::GameAssets, menus, cleanup, asset_loading, game_state, localization::Localization};
 
 pub struct SplashPlugin;
 impl Plugin for SplashPlugin {
@@ -30,6 +30,7 @@ pub fn load(
 ) {
     assets_handler.add_material(&mut game_assets.bevy_icon, "textures/bevy.png", true);
     assets_handler.add_font(&mut game_assets.font, "fonts/monogram.ttf");
+    assets_handler.add_languages(&mut game_assets.languages, "locale/game.languages.ron");
 }
 
 fn tick(
@@ -51,6 +52,7 @@ fn setup(
     game_assets: Res<GameAssets>,
     text_scaler: text_size::TextScaler,
     mut splash_tracker: ResMut<SplashTracker>,
+    localization: Res<Localization>,
 ) {
     splash_tracker.time = 0.0;
 
@@ -107,9 +109,9 @@ fn setup(
                     ..Default::default()
                 },
                 text: Text::from_section(
-                    "made with Bevy",
+                    localization.get("splash.made_with"),
                     TextStyle {
-                        font: game_assets.font.clone(),
+                        font: localization.font(&game_assets),
                         font_size: text_scaler.scale(menus::DEFAULT_FONT_SIZE * 1.2),
                         color: Color::WHITE,
                     })
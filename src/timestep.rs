@@ -0,0 +1,152 @@
+use crate::{AppState, cutscene};
+use bevy::ecs::event::Event;
+use bevy::ecs::schedule::ShouldRun;
+use bevy::prelude::*;
+use bevy::time::{FixedTimestep, FixedTimesteps};
+use bevy::transform::{systems::transform_propagate_system, TransformSystem};
+
+// Gameplay runs in its own stage right after Update at a fixed rate so movement, friction and
+// blade hits come out the same at any frame rate. Entities with an Interpolated component are
+// drawn between their last two simulated transforms so they still move smoothly on screen.
+
+pub const STEP: f32 = 1.0 / 60.0;
+const TIMESTEP_LABEL: &str = "gameplay";
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, StageLabel)]
+pub struct FixedUpdateStage;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+struct RestoreSimulated;
+
+pub struct TimestepPlugin;
+impl Plugin for TimestepPlugin {
+    fn build(&self, app: &mut App) {
+        // TimePlugin normally adds this but headless and replays run without it
+        app.init_resource::<FixedTimesteps>()
+            .insert_resource(FixedTime { step: STEP })
+            .add_stage_after(
+                CoreStage::Update,
+                FixedUpdateStage,
+                SystemStage::parallel()
+                    .with_run_criteria(FixedTimestep::step(STEP as f64).with_label(TIMESTEP_LABEL))
+            )
+            .add_system_set_to_stage(
+                FixedUpdateStage,
+                in_state(AppState::InGame)
+                    .with_system(restore_simulated_transforms.exclusive_system().at_start().label(RestoreSimulated))
+                    // blades and other children need their GlobalTransform to match the step they're checked in
+                    .with_system(transform_propagate_system.exclusive_system().at_start().after(RestoreSimulated))
+                    .with_system(store_simulated_transforms.exclusive_system().at_end())
+            )
+            .add_system_set_to_stage(
+                CoreStage::PostUpdate,
+                in_state(AppState::InGame)
+                    .with_system(interpolate_transforms.before(TransformSystem::TransformPropagate))
+            );
+    }
+}
+
+// Gameplay systems stop stepping as soon as something asks for a cutscene, otherwise
+// how many more steps ran before it was pushed would depend on the frame rate.
+pub fn gameplay() -> SystemSet {
+    SystemSet::new().with_run_criteria(|app_state: Res<State<AppState>>, cutscene_state: Res<cutscene::CutsceneState>| {
+        if *app_state.current() == AppState::InGame && cutscene_state.current.is_none() {
+            ShouldRun::Yes
+        } else {
+            ShouldRun::No
+        }
+    })
+}
+
+// SystemSet::on_update only works in the stage that drives AppState (it loops forever anywhere
+// else), this is the same check for systems in FixedUpdateStage and PostUpdate
+pub fn in_state(state: AppState) -> SystemSet {
+    SystemSet::new().with_run_criteria(move |app_state: Res<State<AppState>>| {
+        if *app_state.current() == state {
+            ShouldRun::Yes
+        } else {
+            ShouldRun::No
+        }
+    })
+}
+
+pub trait FixedEventAppExt {
+    // events that are read in FixedUpdateStage are cleared per step instead of per frame,
+    // otherwise they could be dropped on frames where no step runs. They're swapped after every
+    // reader in the step has had a look, and not at all while gameplay is held for a cutscene or
+    // pause, so whatever was sent just before is still there when it starts again
+    fn add_fixed_event<T: Event>(&mut self) -> &mut Self;
+}
+
+impl FixedEventAppExt for App {
+    fn add_fixed_event<T: Event>(&mut self) -> &mut Self {
+        self.init_resource::<Events<T>>()
+            .add_system_set_to_stage(
+                FixedUpdateStage,
+                gameplay()
+                    .with_system(Events::<T>::update_system.exclusive_system().at_end())
+            )
+    }
+}
+
+// stands in for Time in gameplay systems
+pub struct FixedTime {
+    step: f32,
+}
+
+impl FixedTime {
+    pub fn delta_seconds(&self) -> f32 {
+        self.step
+    }
+}
+
+#[derive(Component, Default)]
+pub struct Interpolated {
+    previous: Option<Transform>,
+    current: Option<Transform>,
+}
+
+impl Interpolated {
+    // for when something is moved rather than walked somewhere, so it isn't drawn sliding across
+    pub fn teleport(&mut self, transform: Transform) {
+        self.previous = Some(transform);
+        self.current = Some(transform);
+    }
+}
+
+fn restore_simulated_transforms(
+    mut interpolated: Query<(&Interpolated, &mut Transform)>,
+) {
+    for (interpolated, mut transform) in &mut interpolated {
+        if let Some(current) = interpolated.current {
+            *transform = current;
+        }
+    }
+}
+
+fn store_simulated_transforms(
+    mut interpolated: Query<(&mut Interpolated, &Transform)>,
+) {
+    for (mut interpolated, transform) in &mut interpolated {
+        interpolated.previous = Some(interpolated.current.unwrap_or(*transform));
+        interpolated.current = Some(*transform);
+    }
+}
+
+fn interpolate_transforms(
+    mut interpolated: Query<(&Interpolated, &mut Transform)>,
+    timesteps: Res<FixedTimesteps>,
+) {
+    let alpha = timesteps.get(TIMESTEP_LABEL)
+                         .map(|timestep| timestep.overstep_percentage() as f32)
+                         .unwrap_or(1.0)
+                         .clamp(0.0, 1.0);
+
+    for (interpolated, mut transform) in &mut interpolated {
+        if let (Some(previous), Some(current)) = (interpolated.previous, interpolated.current) {
+            transform.translation = previous.translation.lerp(current.translation, alpha);
+            transform.rotation = previous.rotation.slerp(current.rotation, alpha);
+            transform.scale = previous.scale.lerp(current.scale, alpha);
+        }
+    }
+}
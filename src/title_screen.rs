@@ -1,12 +1,13 @@
 use crate::{
-    asset_loading, assets::GameAssets, cleanup, game_controller, AppState, shaders,
-    audio::GameAudio, menus, ui::text_size, game_state, cutscene, banter,
+    asset_loading, assets::GameAssets, cleanup, controls, AppState, shaders,
+    audio::GameAudio, menus, ui::text_size, game_state, cutscene, banter, rounds, localization::Localization,
 };
 use bevy::app::AppExit;
 use bevy::core_pipeline::clear_color::ClearColorConfig;
 use bevy::ecs::event::Events;
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
+use serde::{Deserialize, Serialize};
 
 pub struct TitlePlugin;
 impl Plugin for TitlePlugin {
@@ -15,8 +16,7 @@ impl Plugin for TitlePlugin {
             .add_system_set(SystemSet::on_enter(AppState::TitleScreen).with_system(setup))
             .add_system_set(
                 SystemSet::on_update(AppState::TitleScreen)
-                    .with_system(update_menu_buttons.after(handle_controllers))
-                    .with_system(handle_controllers.after(game_controller::store_controller_inputs))
+                    .with_system(update_menu_buttons)
             )
             .add_system_set(
                 SystemSet::on_exit(AppState::TitleScreen).with_system(cleanup::<CleanupMarker>),
@@ -27,7 +27,7 @@ impl Plugin for TitlePlugin {
 #[derive(Component)]
 pub struct CleanupMarker;
 
-#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug)]
+#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Serialize, Deserialize)]
 pub enum MenuAction {
     Up,
     Down,
@@ -36,35 +36,10 @@ pub enum MenuAction {
     Select,
 }
 impl MenuAction {
+    // the left stick comes with it, the saved bindings are swapped in by controls::apply_controls
+    // once it's spawned
     pub fn default_input_map() -> InputMap<MenuAction> {
-        use MenuAction::*;
-        let mut input_map = InputMap::default();
-
-        input_map.set_gamepad(Gamepad { id: 0 });
-
-        input_map.insert(KeyCode::Up, Up);
-        input_map.insert(KeyCode::W, Up);
-        input_map.insert(KeyCode::Z, Up);
-        input_map.insert(GamepadButtonType::DPadUp, Up);
-
-        input_map.insert(KeyCode::Down, Down);
-        input_map.insert(KeyCode::S, Down);
-        input_map.insert(GamepadButtonType::DPadDown, Down);
-
-        input_map.insert(KeyCode::Left, Left);
-        input_map.insert(KeyCode::A, Left);
-        input_map.insert(KeyCode::Q, Left);
-        input_map.insert(GamepadButtonType::DPadLeft, Left);
-
-        input_map.insert(KeyCode::Right, Right);
-        input_map.insert(KeyCode::D, Right);
-        input_map.insert(GamepadButtonType::DPadRight, Right);
-
-        input_map.insert(KeyCode::Space, Select);
-        input_map.insert(KeyCode::Return, Select);
-        input_map.insert(GamepadButtonType::South, Select);
-
-        input_map
+        controls::Controls::default().menu_input_map(None)
     }
 }
 
@@ -75,6 +50,7 @@ pub fn load(
     assets_handler.add_audio(&mut game_assets.titlescreen, "audio/football.ogg");
     assets_handler.add_audio(&mut game_assets.blip, "audio/blip.wav");
     assets_handler.add_font(&mut game_assets.font, "fonts/monogram.ttf");
+    assets_handler.add_rounds(&mut game_assets.rounds, &rounds::rounds_file());
     assets_handler.add_material(
         &mut game_assets.title_screen_logo,
         "textures/logo.png",
@@ -92,10 +68,12 @@ fn setup(
     mut clear_color: ResMut<ClearColor>,
     mut banter_state: ResMut<banter::BanterState>,
     mut cutscene_state: ResMut<cutscene::CutsceneState>,
+    rounds: Res<rounds::Rounds>,
     text_scaler: text_size::TextScaler,
+    localization: Res<Localization>,
 ) {
-    cutscene_state.init(cutscene::Cutscene::Intro);
-    banter_state.reset(&game_assets);
+    cutscene_state.init(rounds.get(0).intro.clone());
+    banter_state.reset();
 
     clear_color.0 = Color::hex("00068a").unwrap(); 
     let image_height = 1280.0;
@@ -194,9 +172,9 @@ fn setup(
                 ..Default::default()
             },
             text: Text::from_section(
-                "by michael ramirez".to_string(),
+                localization.get("title.credit"),
                 TextStyle {
-                    font: game_assets.font.clone(),
+                    font: localization.font(&game_assets),
                     font_size: text_scaler.scale(menus::BY_LINE_FONT_SIZE),
                     color: Color::WHITE,
                 }
@@ -208,14 +186,14 @@ fn setup(
     commands
         .spawn_bundle(NodeBundle {
             style: Style {
-                size: Size::new(Val::Percent(30.0), Val::Percent(25.0)),
+                size: Size::new(Val::Percent(30.0), Val::Percent(40.0)),
                 position_type: PositionType::Relative,
                 justify_content: JustifyContent::Center,
                 flex_direction: FlexDirection::ColumnReverse,
                 margin: UiRect {
                     left: Val::Auto,
                     right: Val::Auto,
-                    top: Val::Percent(60.0),
+                    top: Val::Percent(56.0),
                     ..Default::default()
                 },
                 align_items: AlignItems::FlexStart,
@@ -231,9 +209,37 @@ fn setup(
                     style: Style {
                         position_type: PositionType::Relative,
                         margin: UiRect::all(Val::Auto),
-                        size: Size::new(Val::Percent(100.0), Val::Percent(40.0)),
+                        size: Size::new(Val::Percent(100.0), Val::Percent(22.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    color: menus::NORMAL_BUTTON.into(),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    parent.spawn_bundle(TextBundle {
+                        text: Text::from_section(
+                            localization.get("title.start"),
+                            TextStyle {
+                                font: localization.font(&game_assets),
+                                font_size: text_scaler.scale(menus::BUTTON_LABEL_FONT_SIZE),
+                                color: Color::WHITE,
+                            }
+                        ),
+                        ..Default::default()
+                    });
+                })
+                .insert(CleanupMarker);
+
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Percent(100.0), Val::Percent(22.0)),
+                        margin: UiRect::all(Val::Auto),
                         justify_content: JustifyContent::Center,
                         align_items: AlignItems::Center,
+                        position_type: PositionType::Relative,
                         ..Default::default()
                     },
                     color: menus::NORMAL_BUTTON.into(),
@@ -242,9 +248,9 @@ fn setup(
                 .with_children(|parent| {
                     parent.spawn_bundle(TextBundle {
                         text: Text::from_section(
-                            "Start",
+                            localization.get("title.versus"),
                             TextStyle {
-                                font: game_assets.font.clone(),
+                                font: localization.font(&game_assets),
                                 font_size: text_scaler.scale(menus::BUTTON_LABEL_FONT_SIZE),
                                 color: Color::WHITE,
                             }
@@ -257,7 +263,7 @@ fn setup(
             parent
                 .spawn_bundle(ButtonBundle {
                     style: Style {
-                        size: Size::new(Val::Percent(100.0), Val::Percent(40.0)),
+                        size: Size::new(Val::Percent(100.0), Val::Percent(22.0)),
                         margin: UiRect::all(Val::Auto),
                         justify_content: JustifyContent::Center,
                         align_items: AlignItems::Center,
@@ -270,9 +276,37 @@ fn setup(
                 .with_children(|parent| {
                     parent.spawn_bundle(TextBundle {
                         text: Text::from_section(
-                            "Quit",
+                            localization.get("title.high_scores"),
                             TextStyle {
-                                font: game_assets.font.clone(),
+                                font: localization.font(&game_assets),
+                                font_size: text_scaler.scale(menus::BUTTON_LABEL_FONT_SIZE),
+                                color: Color::WHITE,
+                            }
+                        ),
+                        ..Default::default()
+                    });
+                })
+                .insert(CleanupMarker);
+
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size::new(Val::Percent(100.0), Val::Percent(22.0)),
+                        margin: UiRect::all(Val::Auto),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        position_type: PositionType::Relative,
+                        ..Default::default()
+                    },
+                    color: menus::NORMAL_BUTTON.into(),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    parent.spawn_bundle(TextBundle {
+                        text: Text::from_section(
+                            localization.get("title.quit"),
+                            TextStyle {
+                                font: localization.font(&game_assets),
                                 font_size: text_scaler.scale(menus::BUTTON_LABEL_FONT_SIZE),
                                 color: Color::WHITE,
                             }
@@ -334,42 +368,18 @@ fn update_menu_buttons(
     }
 
     if pressed_button {
-        if *selected_button == 0 {
+        // versus picks its settings in the same options menu, starting from there keeps the mode
+        if *selected_button == 0 || *selected_button == 1 {
             audio.play_sfx(&game_assets.blip);
+            game_state.versus = *selected_button == 1;
             assets_handler.load(AppState::Options, &mut game_assets, &mut game_state);
         }
-        if *selected_button == 1 {
-            exit.send(AppExit);
+        if *selected_button == 2 {
+            audio.play_sfx(&game_assets.blip);
+            assets_handler.load(AppState::HighScores, &mut game_assets, &mut game_state);
         }
-    }
-}
-
-fn handle_controllers(
-    controllers: Res<game_controller::GameController>,
-    mut players: Query<(Entity, &mut ActionState<MenuAction>)>,
-) {
-    for (_, mut action_state) in players.iter_mut() {
-        for (_, just_pressed) in controllers.just_pressed.iter() {
-            // release all buttons
-            // this probably affects durations but for
-            // this game it might not be a big deal
-            action_state.release(MenuAction::Up);
-            action_state.release(MenuAction::Down);
-
-            action_state.release(MenuAction::Select);
-
-            if just_pressed.contains(&game_controller::GameButton::Up) {
-                action_state.press(MenuAction::Up);
-            }
-            if just_pressed.contains(&game_controller::GameButton::Down) {
-                action_state.press(MenuAction::Down);
-            }
-            if just_pressed.contains(&game_controller::GameButton::ActionDown)
-                || just_pressed.contains(&game_controller::GameButton::Start)
-            {
-                action_state.press(MenuAction::Select);
-            }
+        if *selected_button == 3 {
+            exit.send(AppExit);
         }
     }
 }
-